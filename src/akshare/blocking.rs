@@ -0,0 +1,331 @@
+//! 同步版本的 akshare 客户端，不依赖 tokio 运行时，适合脚本和 evcxr 等交互式环境。
+//!
+//! 注意：`reqwest::blocking` 不能在异步运行时内部使用。
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use reqwest::{blocking::Client, Url};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::errors::Result;
+
+use super::{
+    balance_sheet::{self, BalanceSheetData},
+    cash_flow_statement::CashFlowStatementData,
+    daily_bar::{Adjust, Bar, HistQuery},
+    dividend::{self, Dividend},
+    income_statement::IncomeStatementData,
+    industry::{self, IndustryBoard, IndustryMap},
+    retry::RetryPolicy,
+    share_capital::ShareHistory,
+    shared::{by_report_date, SymbolQuery},
+    spot::{self, Quote},
+    transport, Backend, Listing, ReportDate, StatementPeriod, StockCode, StockInfo, Symbol,
+    TransportBuilder, DEFAULT_BASE_URL,
+};
+
+/// 同步版本的 [`Transport`](super::Transport)，由 [`TransportBuilder::build_blocking`] 构建
+#[derive(Clone, Debug)]
+pub struct BlockingTransport {
+    client: Client,
+    base_url: String,
+    backend: Backend,
+    retry: RetryPolicy,
+}
+
+impl BlockingTransport {
+    pub fn new() -> Result<Self> {
+        Self::from(DEFAULT_BASE_URL)
+    }
+
+    pub fn from(url: &str) -> Result<Self> {
+        TransportBuilder::default().base_url(url).build_blocking()
+    }
+
+    pub(crate) fn new_with(
+        client: Client,
+        base_url: String,
+        backend: Backend,
+        retry: RetryPolicy,
+    ) -> Self {
+        Self {
+            client,
+            base_url,
+            backend,
+            retry,
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let body = match &self.backend {
+            Backend::Replay(dir) => transport::replay(dir, &url)?,
+            _ => self.fetch(&url)?,
+        };
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    pub fn get_url<Q>(&self, endpoint: &str, params: Option<&Q>) -> Result<Url>
+    where
+        Q: Serialize,
+    {
+        transport::endpoint_url(&self.base_url, endpoint, params)
+    }
+
+    fn fetch(&self, url: &Url) -> Result<String> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self.send(url) {
+                Err(e) => match self.retry.retry_delay(attempt, &e) {
+                    Some(delay) => std::thread::sleep(delay),
+                    None => return Err(e),
+                },
+                result => return result,
+            }
+        }
+    }
+
+    fn send(&self, url: &Url) -> Result<String> {
+        let response = self.client.get(url.clone()).send()?;
+        let status = response.status();
+        let body = response.text()?;
+        transport::record(&self.backend, url, status, body)
+    }
+}
+
+/// 同步版本的 [`Akshare`](super::Akshare)
+#[derive(Clone, Debug)]
+pub struct BlockingAkshare {
+    pub transport: BlockingTransport,
+}
+
+impl BlockingAkshare {
+    pub fn new() -> Result<Self> {
+        let t = BlockingTransport::new()?;
+        Ok(Self { transport: t })
+    }
+
+    pub fn from(url: &str) -> Result<Self> {
+        let t = BlockingTransport::from(url)?;
+        Ok(Self { transport: t })
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.transport = self.transport.with_retry_policy(retry);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.transport.base_url()
+    }
+
+    pub fn sh_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_sh_name_code")
+    }
+
+    pub fn sz_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_sz_name_code")
+    }
+
+    pub fn bj_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_bj_name_code")
+    }
+
+    pub fn all_a_shares(&self) -> Result<Vec<Listing>> {
+        Ok(super::listings(
+            self.sh_list()?,
+            self.sz_list()?,
+            self.bj_list()?,
+        ))
+    }
+
+    pub fn code_list(&self, point: &str) -> Result<Vec<StockInfo>> {
+        self.transport.get(point, None::<&StockCode>)
+    }
+
+    pub fn get_balance_sheet(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+        self.get_balance_sheet_by_period(symbol, StatementPeriod::Report)
+    }
+
+    /// 按指定口径
+    pub fn get_balance_sheet_by_period(
+        &self,
+        symbol: &StockCode,
+        period: StatementPeriod,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+        let data = self
+            .transport
+            .get(&balance_sheet::endpoint(period)?, Some(symbol))?;
+        Ok(by_report_date(data))
+    }
+
+    pub fn get_income_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+        self.get_income_statement_by_period(symbol, StatementPeriod::Report)
+    }
+
+    /// 按指定口径
+    pub fn get_income_statement_by_period(
+        &self,
+        symbol: &StockCode,
+        period: StatementPeriod,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+        let data = self
+            .transport
+            .get(&period.endpoint("stock_profit_sheet"), Some(symbol))?;
+        Ok(by_report_date(data))
+    }
+
+    pub fn get_cash_flow_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+        self.get_cash_flow_statement_by_period(symbol, StatementPeriod::Report)
+    }
+
+    /// 按指定口径
+    pub fn get_cash_flow_statement_by_period(
+        &self,
+        symbol: &StockCode,
+        period: StatementPeriod,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+        let data = self
+            .transport
+            .get(&period.endpoint("stock_cash_flow_sheet"), Some(symbol))?;
+        Ok(by_report_date(data))
+    }
+
+    /// 日线行情，`start`、`end` 均包含在内
+    pub fn get_daily_bars(
+        &self,
+        symbol: &Symbol,
+        start: NaiveDate,
+        end: NaiveDate,
+        adjust: Adjust,
+    ) -> Result<Vec<Bar>> {
+        self.transport.get(
+            "stock_zh_a_hist",
+            Some(&HistQuery::new(symbol, start, end, adjust)),
+        )
+    }
+
+    /// 历年分红送转，按报告期升序
+    pub fn get_dividends(&self, symbol: &Symbol) -> Result<Vec<Dividend>> {
+        let data = self.transport.get(
+            "stock_fhps_detail_em",
+            Some(&StockCode::from(symbol.code())),
+        )?;
+        Ok(dividend::sorted(data))
+    }
+
+    /// 股本结构变动历史
+    pub fn get_share_changes(&self, symbol: &Symbol) -> Result<ShareHistory> {
+        let data = self.transport.get(
+            "stock_zh_a_gbjg_em",
+            Some(&StockCode::from(symbol.suffixed().as_str())),
+        )?;
+        Ok(ShareHistory::new(data))
+    }
+
+    /// 东方财富行业板块
+    pub fn industries(&self) -> Result<Vec<IndustryBoard>> {
+        self.transport
+            .get("stock_board_industry_name_em", None::<&StockCode>)
+    }
+
+    /// 行业板块成分股，`board` 为板块名称，如 `银行`
+    pub fn industry_constituents(&self, board: &str) -> Result<BTreeMap<Symbol, Quote>> {
+        let data = self.transport.get(
+            "stock_board_industry_cons_em",
+            Some(&SymbolQuery::new(board)),
+        )?;
+        Ok(spot::by_symbol(data))
+    }
+
+    /// 全部行业板块的成分股
+    pub fn industry_map(&self) -> Result<IndustryMap> {
+        let boards = self.industries()?;
+        let constituents = boards
+            .iter()
+            .map(|x| self.industry_constituents(&x.name))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(industry::industry_map(boards, constituents))
+    }
+
+    /// 沪深京 A 股实时行情
+    pub fn spot_quotes(&self) -> Result<BTreeMap<Symbol, Quote>> {
+        let data = self
+            .transport
+            .get("stock_zh_a_spot_em", None::<&StockCode>)?;
+        Ok(spot::by_symbol(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::Akshare;
+    use crate::errors::RedCrabError;
+
+    #[test]
+    fn it_works() {
+        let akshare = BlockingAkshare::from("http://127.0.0.1:1/api/public/")
+            .unwrap()
+            .with_retry_policy(RetryPolicy::none());
+        let err = akshare.get_balance_sheet(&"SZ002027".into()).unwrap_err();
+        assert!(matches!(err, RedCrabError::ReqError(e) if e.is_connect()));
+        assert_eq!(
+            akshare
+                .transport
+                .get_url("stock_info_sh_name_code", None::<&StockCode>)
+                .unwrap()
+                .as_str(),
+            "http://127.0.0.1:1/api/public/stock_info_sh_name_code"
+        );
+    }
+
+    #[test]
+    fn replay_works() {
+        let akshare = Akshare::builder()
+            .backend(Backend::Replay(
+                concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/akshare").into(),
+            ))
+            .build_blocking()
+            .unwrap();
+        let list = akshare.bj_list().unwrap();
+        assert_eq!(list.len(), 2);
+        let data = akshare.get_balance_sheet(&"SZ002027".into()).unwrap();
+        assert_eq!(data.len(), 7);
+        let err = akshare
+            .get_balance_sheet_by_period(&"SZ002027".into(), StatementPeriod::Quarterly)
+            .unwrap_err();
+        assert!(matches!(err, RedCrabError::InvalidParameter(_)));
+
+        let symbol: Symbol = "SZ002027".parse().unwrap();
+        let dividends = akshare.get_dividends(&symbol).unwrap();
+        assert!(dividends
+            .windows(2)
+            .all(|x| x[0].report_date <= x[1].report_date));
+        assert_eq!(akshare.industry_map().unwrap().len(), 4);
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use reqwest::Url;
+
+/// 默认缓存有效期
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// 默认最多缓存的响应数
+pub const DEFAULT_CACHE_ENTRIES: usize = 512;
+
+/// 实时行情类接口，默认不缓存
+pub const REALTIME_ENDPOINTS: [&str; 2] = ["stock_zh_a_spot_em", "stock_board_industry_cons_em"];
+
+/// 接口响应的内存缓存，按 (接口名, 查询参数) 缓存，克隆后共享同一份数据
+///
+/// [`REALTIME_ENDPOINTS`] 默认不缓存，可用 [`with_ttl`](Self::with_ttl) 为单个接口调整有效期。
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    ttl: Duration,
+    ttls: HashMap<String, Duration>,
+    max_entries: usize,
+    entries: Arc<Mutex<HashMap<CacheKey, Entry>>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub endpoint: String,
+    pub query: String,
+}
+
+#[derive(Debug)]
+struct Entry {
+    body: Arc<str>,
+    inserted: Instant,
+}
+
+impl CacheKey {
+    pub fn from_url(url: &Url) -> Self {
+        Self {
+            endpoint: url
+                .path_segments()
+                .and_then(|mut x| x.next_back())
+                .unwrap_or_default()
+                .to_owned(),
+            query: url.query().unwrap_or_default().to_owned(),
+        }
+    }
+}
+
+impl ResponseCache {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        let cache = Self {
+            ttl,
+            ttls: HashMap::new(),
+            max_entries: max_entries.max(1),
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        };
+        REALTIME_ENDPOINTS
+            .iter()
+            .fold(cache, |cache, x| cache.with_ttl(x, Duration::ZERO))
+    }
+
+    /// 单独设置某个接口的有效期，为 0 时该接口不缓存
+    pub fn with_ttl(mut self, endpoint: &str, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint.to_owned(), ttl);
+        self
+    }
+
+    /// 接口的缓存有效期
+    pub fn ttl(&self, endpoint: &str) -> Duration {
+        self.ttls.get(endpoint).copied().unwrap_or(self.ttl)
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<Arc<str>> {
+        let ttl = self.ttl(&key.endpoint);
+        let mut entries = self.lock();
+        match entries.get(key) {
+            Some(entry) if entry.inserted.elapsed() < ttl => Some(entry.body.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: CacheKey, body: Arc<str>) {
+        if self.ttl(&key.endpoint).is_zero() {
+            return;
+        }
+        let mut entries = self.lock();
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            entries.retain(|k, x| x.inserted.elapsed() < self.ttl(&k.endpoint));
+            if entries.len() >= self.max_entries {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, x)| x.inserted)
+                    .map(|(k, _)| k.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            key,
+            Entry {
+                body,
+                inserted: Instant::now(),
+            },
+        );
+    }
+
+    /// 删除某个接口的全部缓存
+    pub fn invalidate(&self, endpoint: &str) {
+        self.lock().retain(|k, _| k.endpoint != endpoint);
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<CacheKey, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_TTL, DEFAULT_CACHE_ENTRIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::{mock::MockServer, retry::RetryPolicy, Akshare};
+
+    fn key(endpoint: &str, query: &str) -> CacheKey {
+        CacheKey {
+            endpoint: endpoint.to_owned(),
+            query: query.to_owned(),
+        }
+    }
+
+    #[test]
+    fn ttl_works() {
+        let cache = ResponseCache::new(Duration::from_millis(50), 10);
+        cache.insert(key("a", "symbol=1"), "[]".into());
+        assert_eq!(cache.get(&key("a", "symbol=1")).as_deref(), Some("[]"));
+        assert!(cache.get(&key("a", "symbol=2")).is_none());
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(cache.get(&key("a", "symbol=1")).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn size_limit_works() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 2);
+        let shared = cache.clone();
+        cache.insert(key("a", "1"), "1".into());
+        std::thread::sleep(Duration::from_millis(2));
+        cache.insert(key("a", "2"), "2".into());
+        shared.insert(key("b", "3"), "3".into());
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key("a", "1")).is_none());
+
+        cache.invalidate("a");
+        assert_eq!(shared.len(), 1);
+        assert!(shared.get(&key("b", "3")).is_some());
+    }
+
+    #[test]
+    fn endpoint_ttl_works() {
+        let cache = ResponseCache::default().with_ttl("a", Duration::from_millis(50));
+        assert_eq!(cache.ttl("a"), Duration::from_millis(50));
+        assert_eq!(cache.ttl("b"), DEFAULT_CACHE_TTL);
+        assert_eq!(cache.ttl("stock_zh_a_spot_em"), Duration::ZERO);
+
+        cache.insert(key("stock_zh_a_spot_em", ""), "[]".into());
+        assert!(cache.is_empty());
+        cache.insert(key("a", ""), "1".into());
+        cache.insert(key("b", ""), "2".into());
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(cache.get(&key("a", "")).is_none());
+        assert!(cache.get(&key("b", "")).is_some());
+    }
+
+    #[tokio::test]
+    async fn one_call_per_statement() {
+        let server = MockServer::with_fixtures(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/akshare"
+        ))
+        .await
+        .unwrap();
+        let client = Akshare::builder()
+            .base_url(&server.base_url())
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        for _ in 0..20 {
+            client.get_balance_sheet(&"SZ002027".into()).await.unwrap();
+            client
+                .clone()
+                .get_income_statement(&"SZ002027".into())
+                .await
+                .unwrap();
+        }
+        assert_eq!(server.hits("stock_balance_sheet_by_report_em"), 1);
+        assert_eq!(server.hits("stock_profit_sheet_by_report_em"), 1);
+    }
+}
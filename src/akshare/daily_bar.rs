@@ -0,0 +1,153 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+use super::{shared::date_from_string, Akshare, Symbol};
+
+impl Akshare {
+    /// 日线行情，`start`、`end` 均包含在内
+    pub async fn get_daily_bars(
+        &self,
+        symbol: &Symbol,
+        start: NaiveDate,
+        end: NaiveDate,
+        adjust: Adjust,
+    ) -> Result<Vec<Bar>> {
+        self.transport
+            .get(
+                "stock_zh_a_hist",
+                Some(&HistQuery::new(symbol, start, end, adjust)),
+            )
+            .await
+    }
+}
+
+/// 复权方式
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Adjust {
+    /// 不复权
+    #[default]
+    None,
+    /// 前复权
+    Qfq,
+    /// 后复权
+    Hfq,
+}
+
+impl Adjust {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Adjust::None => "",
+            Adjust::Qfq => "qfq",
+            Adjust::Hfq => "hfq",
+        }
+    }
+}
+
+/// `stock_zh_a_hist` 的查询参数
+#[derive(Serialize, Debug)]
+pub(crate) struct HistQuery<'a> {
+    symbol: &'a str,
+    period: &'static str,
+    start_date: String,
+    end_date: String,
+    adjust: &'static str,
+}
+
+impl<'a> HistQuery<'a> {
+    pub(crate) fn new(
+        symbol: &'a Symbol,
+        start: NaiveDate,
+        end: NaiveDate,
+        adjust: Adjust,
+    ) -> Self {
+        Self {
+            symbol: symbol.code(),
+            period: "daily",
+            start_date: start.format("%Y%m%d").to_string(),
+            end_date: end.format("%Y%m%d").to_string(),
+            adjust: adjust.as_str(),
+        }
+    }
+}
+
+/// 日线
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Bar {
+    #[serde(alias = "日期", with = "date_from_string")]
+    pub date: NaiveDate,
+    #[serde(alias = "开盘")]
+    pub open: f64,
+    #[serde(alias = "最高")]
+    pub high: f64,
+    #[serde(alias = "最低")]
+    pub low: f64,
+    #[serde(alias = "收盘")]
+    pub close: f64,
+    /// 成交量（手）
+    #[serde(alias = "成交量")]
+    pub volume: f64,
+    /// 成交额（元）
+    #[serde(alias = "成交额")]
+    pub amount: f64,
+    /// 振幅（%）
+    #[serde(alias = "振幅")]
+    pub amplitude: Option<f64>,
+    /// 涨跌幅（%）
+    #[serde(alias = "涨跌幅")]
+    pub change_pct: Option<f64>,
+    /// 涨跌额
+    #[serde(alias = "涨跌额")]
+    pub change: Option<f64>,
+    /// 换手率（%）
+    #[serde(alias = "换手率")]
+    pub turnover: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::akshare::fixture_client;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let client = fixture_client();
+        let bars = client
+            .get_daily_bars(
+                &"002027".parse().unwrap(),
+                date("2022-08-01"),
+                date("2022-08-05"),
+                Adjust::Qfq,
+            )
+            .await
+            .unwrap();
+        assert_eq!(bars.len(), 5);
+        assert_eq!(bars[0].date, date("2022-08-01"));
+        assert_eq!(bars[0].close, 6.10);
+        assert_eq!(bars[1].change_pct, Some(-3.11));
+        assert_eq!(bars[4].turnover, Some(0.56));
+        assert!(bars.iter().all(|x| x.low <= x.open && x.open <= x.high));
+    }
+
+    #[test]
+    fn date_formats_work() {
+        let bar: Bar = serde_json::from_value(json!({
+            "日期": "2022-08-01T00:00:00.000",
+            "开盘": 6.02, "收盘": 6.10, "最高": 6.15, "最低": 5.98,
+            "成交量": 1032541, "成交额": 629876543.0,
+        }))
+        .unwrap();
+        assert_eq!(bar.date, date("2022-08-01"));
+        assert_eq!(bar.turnover, None);
+
+        let back: Bar = serde_json::from_str(&serde_json::to_string(&bar).unwrap()).unwrap();
+        assert_eq!(back, bar);
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+use super::{
+    income_statement::IncomeStatementData,
+    shared::{opt_date_from_string, report_date_from_string},
+    Akshare, Quarter, ReportDate, StockCode, Symbol,
+};
+
+impl Akshare {
+    /// 历年分红送转，按报告期升序
+    pub async fn get_dividends(&self, symbol: &Symbol) -> Result<Vec<Dividend>> {
+        let data: Vec<Dividend> = self
+            .transport
+            .get(
+                "stock_fhps_detail_em",
+                Some(&StockCode::from(symbol.code())),
+            )
+            .await?;
+        Ok(sorted(data))
+    }
+}
+
+/// 按报告期升序排列
+pub(crate) fn sorted(mut data: Vec<Dividend>) -> Vec<Dividend> {
+    data.sort_by(|a, b| a.report_date.cmp(&b.report_date));
+    data
+}
+
+/// 分红送转方案，比例均为每 10 股
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Dividend {
+    #[serde(alias = "报告期", with = "report_date_from_string")]
+    pub report_date: ReportDate,
+    /// 每 10 股送股
+    #[serde(alias = "送转股份-送股比例")]
+    pub bonus_ratio: Option<f64>,
+    /// 每 10 股转增
+    #[serde(alias = "送转股份-转股比例")]
+    pub transfer_ratio: Option<f64>,
+    /// 每 10 股派现（元，含税）
+    #[serde(alias = "现金分红-现金分红比例")]
+    pub cash_ratio: Option<f64>,
+    #[serde(alias = "现金分红-股息率")]
+    pub dividend_yield: Option<f64>,
+    #[serde(alias = "总股本")]
+    pub total_shares: Option<f64>,
+    #[serde(alias = "预案公告日", default, with = "opt_date_from_string")]
+    pub plan_date: Option<NaiveDate>,
+    #[serde(alias = "股权登记日", default, with = "opt_date_from_string")]
+    pub record_date: Option<NaiveDate>,
+    #[serde(alias = "除权除息日", default, with = "opt_date_from_string")]
+    pub ex_date: Option<NaiveDate>,
+    /// 方案进度，如 `实施分配`、`股东大会决议通过`、`不分配`
+    #[serde(alias = "方案进度")]
+    pub progress: Option<String>,
+}
+
+impl Dividend {
+    /// 每股派现（元）
+    pub fn cash_per_share(&self) -> Option<f64> {
+        self.cash_ratio.map(|x| x / 10.0)
+    }
+
+    /// 每股送转股数
+    pub fn shares_per_share(&self) -> Option<f64> {
+        match (self.bonus_ratio, self.transfer_ratio) {
+            (None, None) => None,
+            (a, b) => Some((a.unwrap_or(0.0) + b.unwrap_or(0.0)) / 10.0),
+        }
+    }
+
+    /// 派现总额（元）
+    pub fn cash_total(&self) -> Option<f64> {
+        self.cash_per_share()
+            .zip(self.total_shares)
+            .map(|(a, b)| a * b)
+    }
+
+    /// 方案已实施
+    pub fn is_implemented(&self) -> bool {
+        self.progress.as_deref() == Some("实施分配")
+    }
+
+    /// 方案被否决或终止
+    fn is_cancelled(&self) -> bool {
+        matches!(
+            self.progress.as_deref(),
+            Some("股东大会否决" | "停止实施" | "不分配")
+        )
+    }
+}
+
+/// 一个会计年度的现金分红
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Default)]
+pub struct AnnualDividend {
+    pub year: i32,
+    /// 每股派现合计（元）
+    pub cash_per_share: f64,
+    /// 派现总额合计（元），有方案缺少总股本时为 `None`
+    pub cash_total: Option<f64>,
+}
+
+/// 按报告期所在年度汇总现金分红（中报、年报等合并），不含被否决或终止的方案
+///
+/// 董事会预案、股东大会决议通过等尚未实施的方案也会计入；只统计已实施的方案时先用
+/// [`Dividend::is_implemented`] 过滤，如 `cash_dividends_by_year(dividends.iter().filter(|x| x.is_implemented()))`。
+pub fn cash_dividends_by_year<'a, I>(dividends: I) -> BTreeMap<i32, AnnualDividend>
+where
+    I: IntoIterator<Item = &'a Dividend>,
+{
+    let mut map: BTreeMap<i32, AnnualDividend> = BTreeMap::new();
+    for d in dividends.into_iter().filter(|x| !x.is_cancelled()) {
+        let Some(per_share) = d.cash_per_share() else {
+            continue;
+        };
+        let year = d.report_date.year;
+        let entry = map.entry(year).or_insert_with(|| AnnualDividend {
+            year,
+            cash_per_share: 0.0,
+            cash_total: Some(0.0),
+        });
+        entry.cash_per_share += per_share;
+        entry.cash_total = entry.cash_total.zip(d.cash_total()).map(|(a, b)| a + b);
+    }
+    map
+}
+
+/// 分红率：年度派现总额 / 年报归母净利润，缺少派现总额、年报或净利润不为正的年度不计
+pub fn payout_ratios(
+    dividends: &BTreeMap<i32, AnnualDividend>,
+    income: &BTreeMap<ReportDate, IncomeStatementData>,
+) -> BTreeMap<i32, f64> {
+    dividends
+        .iter()
+        .filter_map(|(year, d)| {
+            let profit = income
+                .get(&ReportDate::new(*year, Quarter::Q4))?
+                .parent_netprofit
+                .filter(|x| *x > 0.0)?;
+            Some((*year, d.cash_total? / profit))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::fixture_client;
+
+    #[tokio::test]
+    async fn it_works() {
+        let client = fixture_client();
+        let symbol: Symbol = "SZ002027".parse().unwrap();
+        let dividends = client.get_dividends(&symbol).await.unwrap();
+        assert_eq!(dividends.len(), 4);
+
+        let d = &dividends[2];
+        assert_eq!(d.report_date, ReportDate::new(2021, Quarter::Q4));
+        assert_eq!(d.cash_per_share(), Some(0.32));
+        assert_eq!(d.shares_per_share(), None);
+        assert_eq!(d.ex_date, NaiveDate::from_ymd_opt(2022, 6, 17));
+        assert!(d.is_implemented());
+        assert_eq!(dividends[3].record_date, None);
+        assert!(!dividends[3].is_implemented());
+    }
+
+    #[tokio::test]
+    async fn payout_ratios_work() {
+        let client = fixture_client();
+        let symbol: Symbol = "SZ002027".parse().unwrap();
+        let dividends = client.get_dividends(&symbol).await.unwrap();
+        let annual = cash_dividends_by_year(&dividends);
+        assert_eq!(annual.keys().copied().collect::<Vec<_>>(), vec![2020, 2021]);
+        assert!((annual[&2021].cash_per_share - 0.37).abs() < 1e-9);
+
+        let income = client
+            .get_income_statement(&(&symbol).into())
+            .await
+            .unwrap();
+        let implemented = cash_dividends_by_year(dividends.iter().filter(|x| x.is_implemented()));
+        assert_eq!(implemented, annual);
+
+        let ratios = payout_ratios(&annual, &income);
+        let expected = 0.37 * 14442199726.0 / 6064163522.44;
+        assert!((ratios[&2021] - expected).abs() < 1e-6);
+        assert!((ratios[&2020] - 0.22 * 14442199726.0 / 4004041837.34).abs() < 1e-6);
+    }
+
+    #[test]
+    fn missing_total_shares_keep_per_share() {
+        let dividend = |year, cash_ratio, total_shares, progress: &str| Dividend {
+            report_date: ReportDate::new(year, Quarter::Q4),
+            bonus_ratio: None,
+            transfer_ratio: None,
+            cash_ratio: Some(cash_ratio),
+            dividend_yield: None,
+            total_shares,
+            plan_date: None,
+            record_date: None,
+            ex_date: None,
+            progress: Some(progress.to_owned()),
+        };
+        let dividends = [
+            dividend(2020, 2.0, Some(100.0), "实施分配"),
+            dividend(2021, 1.0, Some(100.0), "实施分配"),
+            dividend(2021, 3.0, None, "董事会预案"),
+            dividend(2021, 5.0, Some(100.0), "股东大会否决"),
+        ];
+
+        let annual = cash_dividends_by_year(&dividends);
+        assert!((annual[&2021].cash_per_share - 0.4).abs() < 1e-9);
+        assert_eq!(annual[&2021].cash_total, None);
+        assert_eq!(annual[&2020].cash_total, Some(20.0));
+
+        let implemented = cash_dividends_by_year(dividends.iter().filter(|x| x.is_implemented()));
+        assert!((implemented[&2021].cash_per_share - 0.1).abs() < 1e-9);
+        assert_eq!(implemented[&2021].cash_total, Some(10.0));
+    }
+}
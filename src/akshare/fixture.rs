@@ -0,0 +1,189 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::{RedCrabError, Result};
+
+/// 录制的一次请求：请求地址 + 响应
+///
+/// 保存在 `dir/<接口名>/<查询参数>.json`，无查询参数时为 `dir/<接口名>.json`。
+/// 查询参数含有文件名不允许的字符时，文件名附加原始查询参数的哈希，避免不同请求落到同一文件。
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Fixture {
+    pub url: String,
+    pub status: u16,
+    /// 响应体；能解析为 JSON 时保存 JSON，否则保存原始文本
+    pub body: Value,
+    /// `body` 是否为 JSON，为 `false` 时 `body` 是原始文本
+    #[serde(default = "is_json_default", skip_serializing_if = "is_json")]
+    pub json: bool,
+}
+
+fn is_json_default() -> bool {
+    true
+}
+
+/// JSON 响应不写出 `json` 字段，与旧的录制文件保持一致
+fn is_json(json: &bool) -> bool {
+    *json
+}
+
+impl Fixture {
+    pub fn new(url: &Url, status: StatusCode, body: &str) -> Self {
+        let (body, json) = match serde_json::from_str(body) {
+            Ok(body) => (body, true),
+            Err(_) => (Value::String(body.to_owned()), false),
+        };
+        Self {
+            url: url.to_string(),
+            status: status.as_u16(),
+            body,
+            json,
+        }
+    }
+
+    /// 请求对应的录制文件路径
+    pub fn path(dir: &Path, url: &Url) -> PathBuf {
+        let endpoint = url
+            .path_segments()
+            .and_then(|mut x| x.next_back())
+            .unwrap_or_default();
+        match url.query() {
+            Some(query) if !query.is_empty() => {
+                let name = sanitize(query);
+                let name = if name == query {
+                    name
+                } else {
+                    format!("{}+{:016x}", name, fnv1a(query))
+                };
+                dir.join(endpoint).join(format!("{}.json", name))
+            }
+            _ => dir.join(format!("{}.json", endpoint)),
+        }
+    }
+
+    pub fn load(dir: &Path, url: &Url) -> Result<Self> {
+        let path = Self::path(dir, url);
+        let data = fs::read(&path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => RedCrabError::MissingFixture {
+                url: url.to_string(),
+                path: path.clone(),
+            },
+            _ => e.into(),
+        })?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let url = Url::parse(&self.url)?;
+        let path = Self::path(dir, &url);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    /// 还原响应体文本
+    pub fn text(&self) -> String {
+        match &self.body {
+            Value::String(s) if !self.json => s.to_owned(),
+            body => body.to_string(),
+        }
+    }
+}
+
+/// 文件名用的稳定哈希，不依赖 `std` 哈希的实现
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn sanitize(query: &str) -> String {
+    query
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "=&%._-".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_works() {
+        let dir = Path::new("fixtures");
+        let url = Url::parse("http://127.0.0.1:8080/api/public/stock_info_sh_name_code").unwrap();
+        assert_eq!(
+            Fixture::path(dir, &url),
+            Path::new("fixtures/stock_info_sh_name_code.json")
+        );
+        let url = Url::parse(
+            "http://127.0.0.1:8080/api/public/stock_balance_sheet_by_report_em?symbol=SZ002027",
+        )
+        .unwrap();
+        assert_eq!(
+            Fixture::path(dir, &url),
+            Path::new("fixtures/stock_balance_sheet_by_report_em/symbol=SZ002027.json")
+        );
+    }
+
+    #[test]
+    fn save_and_load_works() {
+        let dir = std::env::temp_dir().join(format!("red-crab-fixture-{}", std::process::id()));
+        let url = Url::parse("http://127.0.0.1:8080/api/public/x?symbol=A*B").unwrap();
+
+        Fixture::new(&url, StatusCode::OK, r#"[{"a":1}]"#)
+            .save(&dir)
+            .unwrap();
+        let fixture = Fixture::load(&dir, &url).unwrap();
+        assert_eq!(fixture.status(), StatusCode::OK);
+        assert_eq!(fixture.text(), r#"[{"a":1}]"#);
+
+        Fixture::new(&url, StatusCode::BAD_REQUEST, "bad symbol")
+            .save(&dir)
+            .unwrap();
+        assert_eq!(Fixture::load(&dir, &url).unwrap().text(), "bad symbol");
+
+        // 200 的非 JSON 响应原样回放
+        Fixture::new(&url, StatusCode::OK, "plain text")
+            .save(&dir)
+            .unwrap();
+        assert_eq!(Fixture::load(&dir, &url).unwrap().text(), "plain text");
+
+        // 只有被替换的字符不同的查询参数不会互相覆盖
+        let other = Url::parse("http://127.0.0.1:8080/api/public/x?symbol=A!B").unwrap();
+        assert_ne!(Fixture::path(&dir, &url), Fixture::path(&dir, &other));
+        Fixture::new(&other, StatusCode::OK, "[]")
+            .save(&dir)
+            .unwrap();
+        assert_eq!(Fixture::load(&dir, &url).unwrap().text(), "plain text");
+        assert_eq!(Fixture::load(&dir, &other).unwrap().text(), "[]");
+
+        let missing = Url::parse("http://127.0.0.1:8080/api/public/x?symbol=C").unwrap();
+        match Fixture::load(&dir, &missing) {
+            Err(RedCrabError::MissingFixture { url, path }) => {
+                assert_eq!(url, missing.as_str());
+                assert_eq!(path, dir.join("x").join("symbol=C.json"));
+            }
+            r => panic!("unexpected {:?}", r),
+        }
+        let _ = fs::remove_dir_all(dir);
+    }
+}
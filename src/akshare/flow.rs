@@ -0,0 +1,214 @@
+//! 由年初至今累计的利润表、现金流量表推算单季度与 TTM（近十二个月）数据。
+//!
+//! 推算逐字段进行：
+//! - 数值字段按 `本期 - 基期`（单季度）或 `本期 + 上年年报 - 上年同期`（TTM）计算，任一项缺失则为 `None`；
+//! - `END_*` 为期末余额，保留本期值；`BEGIN_*` 为期初余额，取基期的 `END_*`；
+//! - `*_YOY`、`*_QOQ` 等比率无法推算，置为 `None`；
+//! - 其余非数值字段保留本期值。
+//!
+//! 一季度的单季度值与四季度的 TTM 值就是累计值本身，原样保留（包括比率）。
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::errors::Result;
+
+use super::{Quarter, ReportDate};
+
+/// 推算结果
+#[derive(Clone, Debug)]
+pub struct Derived<T> {
+    /// 报告期 -> 推算值
+    pub values: BTreeMap<ReportDate, T>,
+    /// 无法推算的报告期 -> 缺失的报告期
+    pub missing: BTreeMap<ReportDate, Vec<ReportDate>>,
+}
+
+/// 单季度数据：一季度为累计值本身，其余为本期累计减上一季度累计
+pub fn single_quarter<'a, T, I>(history: I) -> Result<Derived<T>>
+where
+    T: Serialize + DeserializeOwned + 'a,
+    I: IntoIterator<Item = (&'a ReportDate, &'a T)>,
+{
+    derive(history, |date| match date.quarter {
+        Quarter::Q1 => vec![],
+        _ => vec![date.previous_quarter()],
+    })
+}
+
+/// TTM 数据：四季度为累计值本身，其余为本期累计 + 上年年报 - 上年同期累计
+pub fn ttm<'a, T, I>(history: I) -> Result<Derived<T>>
+where
+    T: Serialize + DeserializeOwned + 'a,
+    I: IntoIterator<Item = (&'a ReportDate, &'a T)>,
+{
+    derive(history, |date| match date.quarter {
+        Quarter::Q4 => vec![],
+        _ => vec![date.year_ago(), ReportDate::new(date.year - 1, Quarter::Q4)],
+    })
+}
+
+/// `inputs` 返回推算所需的报告期：空表示直接取本期值，
+/// 否则第一个为基期（被减），其余为需要加上的报告期
+fn derive<'a, T, I, F>(history: I, inputs: F) -> Result<Derived<T>>
+where
+    T: Serialize + DeserializeOwned + 'a,
+    I: IntoIterator<Item = (&'a ReportDate, &'a T)>,
+    F: Fn(&ReportDate) -> Vec<ReportDate>,
+{
+    let mut rows = HashMap::new();
+    for (date, data) in history {
+        rows.insert(date.clone(), serde_json::to_value(data)?);
+    }
+
+    let mut values = BTreeMap::new();
+    let mut missing = BTreeMap::new();
+    for (date, current) in rows.iter() {
+        let needs = inputs(date);
+        let absent: Vec<ReportDate> = needs
+            .iter()
+            .filter(|x| !rows.contains_key(x))
+            .cloned()
+            .collect();
+        if !absent.is_empty() {
+            missing.insert(date.clone(), absent);
+            continue;
+        }
+
+        let value = match needs.split_first() {
+            None => current.clone(),
+            Some((base, adds)) => {
+                let adds: Vec<&Value> = adds.iter().map(|x| &rows[x]).collect();
+                combine(current, &rows[base], &adds)
+            }
+        };
+        values.insert(date.clone(), serde_json::from_value(value)?);
+    }
+
+    Ok(Derived { values, missing })
+}
+
+/// `current + adds - base`
+fn combine(current: &Value, base: &Value, adds: &[&Value]) -> Value {
+    let current = match current.as_object() {
+        Some(current) => current,
+        None => return current.clone(),
+    };
+
+    let mut out = Map::with_capacity(current.len());
+    for (key, value) in current {
+        let derived = if key.ends_with("_YOY") || key.ends_with("_QOQ") {
+            Value::Null
+        } else if let Some(rest) = key.strip_prefix("BEGIN_") {
+            base.get(format!("END_{}", rest))
+                .cloned()
+                .unwrap_or(Value::Null)
+        } else if key.starts_with("END_") || !value.is_number() {
+            value.clone()
+        } else {
+            let mut sum = value.as_f64();
+            for add in adds {
+                sum = sum
+                    .zip(add.get(key).and_then(Value::as_f64))
+                    .map(|(a, b)| a + b);
+            }
+            sum.zip(base.get(key).and_then(Value::as_f64))
+                .map(|(a, b)| a - b)
+                .map_or(Value::Null, Value::from)
+        };
+        out.insert(key.clone(), derived);
+    }
+    Value::Object(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::akshare::{
+        cash_flow_statement::CashFlowStatementData, fixture_client,
+        income_statement::IncomeStatementData,
+    };
+
+    fn assert_close(a: Option<f64>, b: Option<f64>) {
+        assert!((a.unwrap() - b.unwrap()).abs() < 0.01, "{:?} != {:?}", a, b);
+    }
+
+    #[tokio::test]
+    async fn single_quarter_works() {
+        let client = fixture_client();
+        let symbol = "SZ002027".into();
+        let report = client.get_income_statement(&symbol).await.unwrap();
+        let quarterly = client
+            .get_income_statement_quarterly(&symbol)
+            .await
+            .unwrap();
+
+        let derived = single_quarter::<IncomeStatementData, _>(&report).unwrap();
+        assert_eq!(derived.values.len(), quarterly.len());
+        for (date, data) in derived.values.iter() {
+            let expected = &quarterly[date];
+            assert_close(data.total_operate_income, expected.total_operate_income);
+            assert_close(data.parent_netprofit, expected.parent_netprofit);
+            if date.quarter != Quarter::Q1 {
+                assert_eq!(data.parent_netprofit_yoy, None);
+            }
+        }
+        assert_eq!(
+            derived.missing[&ReportDate::new(2020, Quarter::Q4)],
+            vec![ReportDate::new(2020, Quarter::Q3)]
+        );
+
+        let report = client.get_cash_flow_statement(&symbol).await.unwrap();
+        let derived = single_quarter::<CashFlowStatementData, _>(&report).unwrap();
+        assert_close(
+            derived.values[&ReportDate::new(2022, Quarter::Q2)].netcash_operate,
+            Some(1438991568.28),
+        );
+    }
+
+    #[tokio::test]
+    async fn ttm_works() {
+        let client = fixture_client();
+        let report = client
+            .get_income_statement(&"SZ002027".into())
+            .await
+            .unwrap();
+
+        let derived = ttm::<IncomeStatementData, _>(&report).unwrap();
+        assert_close(
+            derived.values[&ReportDate::new(2022, Quarter::Q2)].parent_netprofit,
+            Some(1412245113.38 + 6064163522.44 - 2960712330.52),
+        );
+        assert_eq!(
+            derived.values[&ReportDate::new(2021, Quarter::Q4)].parent_netprofit,
+            Some(6064163522.44)
+        );
+        assert_eq!(
+            derived.missing[&ReportDate::new(2021, Quarter::Q3)],
+            vec![ReportDate::new(2020, Quarter::Q3)]
+        );
+        assert_eq!(derived.values.len() + derived.missing.len(), report.len());
+    }
+
+    #[test]
+    fn balances_are_not_summed() {
+        let q1 =
+            json!({"NETCASH_OPERATE": 10.0, "BEGIN_CCE": 100.0, "END_CCE": 110.0, "NOTE": "a"});
+        let q2 =
+            json!({"NETCASH_OPERATE": 25.0, "BEGIN_CCE": 100.0, "END_CCE": 125.0, "NOTE": "b"});
+        let history = BTreeMap::from([
+            (ReportDate::new(2022, Quarter::Q1), q1),
+            (ReportDate::new(2022, Quarter::Q2), q2),
+        ]);
+
+        let derived = single_quarter::<Value, _>(&history).unwrap();
+        assert_eq!(
+            derived.values[&ReportDate::new(2022, Quarter::Q2)],
+            json!({"NETCASH_OPERATE": 15.0, "BEGIN_CCE": 110.0, "END_CCE": 125.0, "NOTE": "b"})
+        );
+    }
+}
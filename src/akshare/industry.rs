@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+use super::{
+    shared::SymbolQuery,
+    spot::{self, Quote},
+    Akshare, StockCode, Symbol,
+};
+
+impl Akshare {
+    /// 东方财富行业板块
+    pub async fn industries(&self) -> Result<Vec<IndustryBoard>> {
+        self.transport
+            .get("stock_board_industry_name_em", None::<&StockCode>)
+            .await
+    }
+
+    /// 行业板块成分股及其实时行情，`board` 为板块名称，如 `银行`；默认不经过响应缓存
+    pub async fn industry_constituents(&self, board: &str) -> Result<BTreeMap<Symbol, Quote>> {
+        let data: Vec<Quote> = self
+            .transport
+            .get(
+                "stock_board_industry_cons_em",
+                Some(&SymbolQuery::new(board)),
+            )
+            .await?;
+        Ok(spot::by_symbol(data))
+    }
+
+    /// 全部行业板块的成分股
+    pub async fn industry_map(&self) -> Result<IndustryMap> {
+        let boards = self.industries().await?;
+        let constituents = futures::future::try_join_all(
+            boards.iter().map(|x| self.industry_constituents(&x.name)),
+        )
+        .await?;
+        Ok(industry_map(boards, constituents))
+    }
+}
+
+/// 由板块列表和各板块的成分股（顺序一致）构造行业映射
+pub(crate) fn industry_map(
+    boards: Vec<IndustryBoard>,
+    constituents: Vec<BTreeMap<Symbol, Quote>>,
+) -> IndustryMap {
+    IndustryMap::new(
+        boards
+            .into_iter()
+            .zip(constituents)
+            .map(|(board, cons)| (board.name, cons.into_keys())),
+    )
+}
+
+/// 行业板块
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct IndustryBoard {
+    #[serde(alias = "板块名称")]
+    pub name: String,
+    #[serde(alias = "板块代码")]
+    pub code: String,
+    /// 涨跌幅（%）
+    #[serde(alias = "涨跌幅")]
+    pub change_pct: Option<f64>,
+    /// 总市值（元）
+    #[serde(alias = "总市值")]
+    pub total_market_cap: Option<f64>,
+    /// 换手率（%）
+    #[serde(alias = "换手率")]
+    pub turnover: Option<f64>,
+}
+
+/// 股票所属行业，用于按行业分组或中性化
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct IndustryMap {
+    industries: BTreeMap<Symbol, String>,
+}
+
+impl IndustryMap {
+    /// 由（行业，成分股）构造，同一股票出现在多个行业时保留第一个
+    pub fn new<I, S>(boards: I) -> Self
+    where
+        I: IntoIterator<Item = (String, S)>,
+        S: IntoIterator<Item = Symbol>,
+    {
+        let mut industries = BTreeMap::new();
+        for (name, symbols) in boards {
+            for symbol in symbols {
+                industries.entry(symbol).or_insert_with(|| name.clone());
+            }
+        }
+        Self { industries }
+    }
+
+    /// 股票所属行业
+    pub fn industry(&self, symbol: &Symbol) -> Option<&str> {
+        self.industries.get(symbol).map(String::as_str)
+    }
+
+    /// 行业的全部成分股
+    pub fn members(&self, industry: &str) -> Vec<&Symbol> {
+        self.industries
+            .iter()
+            .filter(|(_, x)| x.as_str() == industry)
+            .map(|(symbol, _)| symbol)
+            .collect()
+    }
+
+    /// 按行业分组，没有行业的股票被跳过
+    pub fn group<'a, I>(&self, symbols: I) -> BTreeMap<&str, Vec<&'a Symbol>>
+    where
+        I: IntoIterator<Item = &'a Symbol>,
+    {
+        let mut groups: BTreeMap<&str, Vec<&'a Symbol>> = BTreeMap::new();
+        for symbol in symbols {
+            if let Some(industry) = self.industry(symbol) {
+                groups.entry(industry).or_default().push(symbol);
+            }
+        }
+        groups
+    }
+
+    pub fn len(&self) -> usize {
+        self.industries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.industries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::fixture_client;
+
+    #[tokio::test]
+    async fn it_works() {
+        let client = fixture_client();
+        let boards = client.industries().await.unwrap();
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards[1].name, "银行");
+        assert_eq!(boards[1].code, "BK0475");
+
+        let cons = client.industry_constituents("银行").await.unwrap();
+        let symbols: Vec<String> = cons.keys().map(|x| x.prefixed()).collect();
+        assert_eq!(symbols, vec!["SH600036", "SH601398"]);
+        assert_eq!(cons.values().next().unwrap().pb, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn industry_map_works() {
+        let map = fixture_client().industry_map().await.unwrap();
+        assert_eq!(map.len(), 4);
+
+        let s = |x: &str| x.parse::<Symbol>().unwrap();
+        assert_eq!(map.industry(&s("SZ002027")), Some("文化传媒"));
+        assert_eq!(map.industry(&s("SH600519")), None);
+        assert_eq!(map.members("银行").len(), 2);
+
+        let symbols = [s("SZ002027"), s("SH600036"), s("SZ300413"), s("SH600519")];
+        let groups = map.group(&symbols);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["文化传媒"], vec![&symbols[0], &symbols[2]]);
+        assert_eq!(groups["银行"], vec![&symbols[1]]);
+    }
+}
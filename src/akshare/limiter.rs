@@ -0,0 +1,91 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+/// 令牌桶限流器，克隆后共享同一个令牌桶
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// 每秒最多 `rate` 个请求，允许瞬时突发 `burst` 个
+    pub fn new(rate: f64, burst: u32) -> Self {
+        let rate = rate.max(f64::MIN_POSITIVE);
+        let burst = f64::from(burst.max(1));
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                rate,
+                burst,
+                tokens: burst,
+                updated: Instant::now(),
+            })),
+        }
+    }
+
+    /// 获取一个令牌，令牌不足时等待
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+            bucket.reserve()
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl Bucket {
+    /// 预占一个令牌，返回需要等待的时间
+    fn reserve(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.updated = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(5.0, 5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn acquire_works() {
+        let limiter = RateLimiter::new(10.0, 2);
+        let shared = limiter.clone();
+        let start = Instant::now();
+        limiter.acquire().await;
+        shared.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        limiter.acquire().await;
+        shared.acquire().await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(200), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(1), "{:?}", elapsed);
+    }
+}
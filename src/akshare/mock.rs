@@ -0,0 +1,384 @@
+//! 模拟 akshare HTTP 服务，用于集成测试（`test-support` feature）
+//!
+//! 优先返回注入的故障，其次是 [`MockServer::route`] 注册的数据，最后回放 fixture 目录中的录制文件。
+
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use reqwest::{StatusCode, Url};
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use crate::errors::Result;
+
+use super::fixture::Fixture;
+
+/// 注入的故障
+#[derive(Clone, Debug)]
+pub enum Fault {
+    /// 返回指定状态码，响应体为空
+    Status(u16),
+    /// 返回 400 及响应体
+    BadRequest(String),
+    /// 延迟后正常响应
+    Delay(Duration),
+}
+
+#[derive(Default)]
+struct State {
+    fixtures: Option<PathBuf>,
+    routes: HashMap<String, Value>,
+    faults: HashMap<String, VecDeque<Fault>>,
+    hits: HashMap<String, usize>,
+}
+
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// 在随机端口启动
+    pub async fn start() -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let shared = state.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = shared.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve(stream, state).await {
+                        tracing::debug!("mock server connection failed: {}", e);
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            addr,
+            state,
+            handle,
+        })
+    }
+
+    /// 启动并回放 `dir` 中的录制文件
+    pub async fn with_fixtures<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let server = Self::start().await?;
+        server.lock().fixtures = Some(dir.as_ref().to_path_buf());
+        Ok(server)
+    }
+
+    /// 传给 `Akshare::from` 的服务地址
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api/public/", self.addr)
+    }
+
+    /// 接口返回固定数据
+    pub fn route(&self, endpoint: &str, body: Value) {
+        self.lock().routes.insert(endpoint.to_owned(), body);
+    }
+
+    /// 接口接下来的 `times` 次请求返回故障
+    pub fn fail(&self, endpoint: &str, fault: Fault, times: usize) {
+        let mut state = self.lock();
+        let faults = state.faults.entry(endpoint.to_owned()).or_default();
+        faults.extend(std::iter::repeat_n(fault, times));
+    }
+
+    /// 接口收到的请求次数
+    pub fn hits(&self, endpoint: &str) -> usize {
+        self.lock().hits.get(endpoint).copied().unwrap_or_default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn serve(mut stream: TcpStream, state: Arc<Mutex<State>>) -> Result<()> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let head_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).to_string();
+    let content_length = head
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or_default();
+    while buf.len() < head_end + content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let target = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+    let url = Url::parse(&format!("http://mock{}", target))?;
+    let endpoint = url
+        .path_segments()
+        .and_then(|mut x| x.next_back())
+        .unwrap_or_default()
+        .to_owned();
+
+    let (fault, route, fixtures) = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        *state.hits.entry(endpoint.clone()).or_default() += 1;
+        let fault = state.faults.get_mut(&endpoint).and_then(|x| x.pop_front());
+        (
+            fault,
+            state.routes.get(&endpoint).cloned(),
+            state.fixtures.clone(),
+        )
+    };
+
+    let (status, body) = match fault {
+        Some(Fault::Status(code)) => (
+            StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            String::new(),
+        ),
+        Some(Fault::BadRequest(body)) => (StatusCode::BAD_REQUEST, body),
+        fault => {
+            if let Some(Fault::Delay(delay)) = fault {
+                tokio::time::sleep(delay).await;
+            }
+            match (route, fixtures) {
+                (Some(body), _) => (StatusCode::OK, body.to_string()),
+                (None, Some(dir)) => match Fixture::load(&dir, &url) {
+                    Ok(fixture) => (fixture.status(), fixture.text()),
+                    Err(e) => (StatusCode::NOT_FOUND, e.to_string()),
+                },
+                (None, None) => (StatusCode::NOT_FOUND, String::new()),
+            }
+        }
+    };
+
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or_default(),
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        akshare::{
+            blocking::BlockingAkshare, retry::RetryPolicy, Akshare, Backend, Quarter, ReportDate,
+        },
+        errors::RedCrabError,
+    };
+
+    fn fixtures() -> PathBuf {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/akshare").into()
+    }
+
+    fn client(server: &MockServer) -> Akshare {
+        Akshare::builder()
+            .base_url(&server.base_url())
+            .retry_policy(RetryPolicy::none())
+            .rate_limiter(None)
+            .cache(None)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fixtures_work() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        let client = client(&server);
+        assert_eq!(client.sh_list().await.unwrap().len(), 3);
+        let s = client.get_balance_sheet(&"SZ002027".into()).await.unwrap();
+        assert_eq!(s.len(), 7);
+        assert_eq!(server.hits("stock_balance_sheet_by_report_em"), 1);
+
+        server.route("stock_info_sh_name_code", json!([]));
+        assert!(client.sh_list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn error_mapping_works() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        let client = client(&server);
+        let point = "stock_info_sh_name_code";
+        server.fail(point, Fault::Status(500), 1);
+        server.fail(point, Fault::Status(503), 1);
+        server.fail(point, Fault::Status(401), 1);
+        server.fail(point, Fault::BadRequest("symbol is invalid".into()), 1);
+        server.fail(point, Fault::Status(404), 1);
+
+        let err = client.sh_list().await.unwrap_err();
+        assert!(matches!(err, RedCrabError::InternalServerError()));
+        let err = client.sh_list().await.unwrap_err();
+        assert!(matches!(err, RedCrabError::ServiceUnavailable()));
+        let err = client.sh_list().await.unwrap_err();
+        assert!(matches!(err, RedCrabError::Unauthorized()));
+        let err = client.sh_list().await.unwrap_err();
+        assert!(matches!(err, RedCrabError::InvalidParameter(body) if body == "symbol is invalid"));
+        let err = client.sh_list().await.unwrap_err();
+        assert!(matches!(err, RedCrabError::UnkownResponse(_)));
+        assert!(client.sh_list().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn retry_works() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        let policy = RetryPolicy::default()
+            .max_attempts(3)
+            .backoff(Duration::from_millis(10), Duration::from_millis(50));
+        let client = client(&server).with_retry_policy(policy);
+        let point = "stock_balance_sheet_by_report_em";
+
+        server.fail(point, Fault::Status(503), 2);
+        assert!(client.get_balance_sheet(&"SZ002027".into()).await.is_ok());
+        assert_eq!(server.hits(point), 3);
+
+        server.fail(point, Fault::Status(500), 3);
+        let err = client
+            .get_balance_sheet(&"SZ002027".into())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RedCrabError::InternalServerError()));
+        assert_eq!(server.hits(point), 6);
+
+        server.fail(point, Fault::Status(401), 1);
+        assert!(client.get_balance_sheet(&"SZ002027".into()).await.is_err());
+        assert_eq!(server.hits(point), 7);
+    }
+
+    #[tokio::test]
+    async fn slow_response_works() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        let client = Akshare::builder()
+            .base_url(&server.base_url())
+            .timeout(Duration::from_millis(100))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        server.fail(
+            "stock_info_sz_name_code",
+            Fault::Delay(Duration::from_millis(500)),
+            1,
+        );
+        let err = client.sz_list().await.unwrap_err();
+        assert!(matches!(err, RedCrabError::ReqError(e) if e.is_timeout()));
+
+        server.fail(
+            "stock_info_sz_name_code",
+            Fault::Delay(Duration::from_millis(10)),
+            1,
+        );
+        assert_eq!(client.sz_list().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn record_works() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        let dir = std::env::temp_dir().join(format!("red-crab-mock-{}", std::process::id()));
+        let client = Akshare::builder()
+            .base_url(&server.base_url())
+            .backend(Backend::Record(dir.clone()))
+            .build()
+            .unwrap();
+        client
+            .get_income_statement(&"SZ002027".into())
+            .await
+            .unwrap();
+
+        let replay = Akshare::builder()
+            .backend(Backend::Replay(dir.clone()))
+            .build()
+            .unwrap();
+        let s = replay
+            .get_income_statement(&"SZ002027".into())
+            .await
+            .unwrap();
+        assert_eq!(s.len(), 7);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn blocking_uses_builder_settings() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        server.fail(
+            "stock_info_sh_name_code",
+            Fault::Delay(Duration::from_millis(500)),
+            1,
+        );
+        let url = server.base_url();
+        let err = tokio::task::spawn_blocking(move || {
+            Akshare::builder()
+                .base_url(&url)
+                .timeout(Duration::from_millis(100))
+                .retry_policy(RetryPolicy::none())
+                .build_blocking()
+                .unwrap()
+                .sh_list()
+        })
+        .await
+        .unwrap()
+        .unwrap_err();
+        assert!(matches!(err, RedCrabError::ReqError(e) if e.is_timeout()));
+    }
+
+    #[tokio::test]
+    async fn blocking_works() {
+        let server = MockServer::with_fixtures(fixtures()).await.unwrap();
+        let url = server.base_url();
+        let s = tokio::task::spawn_blocking(move || {
+            BlockingAkshare::from(&url)
+                .unwrap()
+                .get_cash_flow_statement(&"SZ002027".into())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            s.get(&ReportDate::new(2021, Quarter::Q4))
+                .unwrap()
+                .netcash_operate,
+            Some(7334418226.91)
+        );
+    }
+}
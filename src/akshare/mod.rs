@@ -1,18 +1,41 @@
 pub mod balance_sheet;
+pub mod blocking;
+pub mod cache;
 pub mod cash_flow_statement;
+pub mod daily_bar;
+pub mod dividend;
+pub mod fixture;
+pub mod flow;
 pub mod income_statement;
+pub mod industry;
+pub mod limiter;
+#[cfg(any(test, feature = "test-support"))]
+pub mod mock;
 pub mod model;
+pub mod retry;
+pub mod share_capital;
 pub mod shared;
+pub mod single_flight;
+pub mod spot;
+pub mod symbol;
 mod transport;
+pub mod universe;
 
-use std::fmt;
+use std::{fmt, str::FromStr, time::Duration};
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 
-use crate::errors::Result;
+use crate::errors::{RedCrabError, Result};
 
-use self::transport::Transport;
+use self::{
+    blocking::BlockingAkshare, cache::ResponseCache, limiter::RateLimiter, retry::RetryPolicy,
+};
+
+pub use self::symbol::{Board, Exchange, Symbol};
+pub use self::transport::{
+    Backend, Transport, TransportBuilder, DEFAULT_BASE_URL, DEFAULT_MAX_IN_FLIGHT,
+};
 
 #[derive(Clone)]
 pub struct Akshare {
@@ -30,6 +53,39 @@ impl Akshare {
         Ok(Self { transport: t })
     }
 
+    pub fn builder() -> AkshareBuilder {
+        AkshareBuilder::default()
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.transport = self.transport.with_retry_policy(retry);
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, limiter: Option<RateLimiter>) -> Self {
+        self.transport = self.transport.with_rate_limiter(limiter);
+        self
+    }
+
+    pub fn with_max_in_flight(mut self, max_in_flight: Option<usize>) -> Self {
+        self.transport = self.transport.with_max_in_flight(max_in_flight);
+        self
+    }
+
+    pub fn with_cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.transport = self.transport.with_cache(cache);
+        self
+    }
+
+    /// 响应缓存，可用于手动失效
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.transport.cache()
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.transport.base_url()
+    }
+
     pub async fn sh_list(&self) -> Result<Vec<StockInfo>> {
         self.code_list("stock_info_sh_name_code").await
     }
@@ -38,12 +94,105 @@ impl Akshare {
         self.code_list("stock_info_sz_name_code").await
     }
 
+    /// 北交所股票列表
+    pub async fn bj_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_bj_name_code").await
+    }
+
+    /// 沪深京全部 A 股
+    pub async fn all_a_shares(&self) -> Result<Vec<Listing>> {
+        let (sh, sz, bj) = futures::try_join!(self.sh_list(), self.sz_list(), self.bj_list())?;
+        Ok(listings(sh, sz, bj))
+    }
+
     pub async fn code_list(&self, point: &str) -> Result<Vec<StockInfo>> {
         let data: Vec<StockInfo> = self.transport.get(point, None::<&StockCode>).await?;
         Ok(data)
     }
 }
 
+/// [`Akshare`] 构建器，参数转发给 [`TransportBuilder`]
+#[derive(Clone, Debug, Default)]
+pub struct AkshareBuilder {
+    transport: TransportBuilder,
+}
+
+impl AkshareBuilder {
+    pub fn base_url(mut self, url: &str) -> Self {
+        self.transport = self.transport.base_url(url);
+        self
+    }
+
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.transport = self.transport.backend(backend);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.transport = self.transport.connect_timeout(timeout);
+        self
+    }
+
+    /// 单次请求的总超时时间，见 [`TransportBuilder::timeout`]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.transport = self.transport.timeout(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.transport = self.transport.proxy(url);
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.transport = self.transport.header(name, value);
+        self
+    }
+
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.transport = self.transport.gzip(enable);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.transport = self.transport.user_agent(user_agent);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.transport = self.transport.retry_policy(retry);
+        self
+    }
+
+    pub fn rate_limiter(mut self, limiter: Option<RateLimiter>) -> Self {
+        self.transport = self.transport.rate_limiter(limiter);
+        self
+    }
+
+    pub fn max_in_flight(mut self, max_in_flight: Option<usize>) -> Self {
+        self.transport = self.transport.max_in_flight(max_in_flight);
+        self
+    }
+
+    pub fn cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.transport = self.transport.cache(cache);
+        self
+    }
+
+    pub fn build(self) -> Result<Akshare> {
+        Ok(Akshare {
+            transport: self.transport.build()?,
+        })
+    }
+
+    /// 用相同设置构建同步客户端
+    pub fn build_blocking(self) -> Result<BlockingAkshare> {
+        Ok(BlockingAkshare {
+            transport: self.transport.build_blocking()?,
+        })
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct StockInfo {
     #[serde(alias = "证券代码")]
@@ -57,12 +206,57 @@ pub struct StockInfo {
     pub time: String,
 }
 
+impl StockInfo {
+    /// 解析上市日期，支持 `2001-08-27`、`20010827` 和 `2001-08-27 00:00:00`
+    pub fn listing_date(&self) -> Option<NaiveDate> {
+        let time = self.time.trim();
+        let date = time.split_whitespace().next().unwrap_or(time);
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .or_else(|_| NaiveDate::parse_from_str(date, "%Y%m%d"))
+            .ok()
+    }
+}
+
+/// A 股股票，由各交易所的股票列表统一而来
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Listing {
+    pub symbol: Symbol,
+    pub name: String,
+    /// 上市日期，无法解析时为 `None`
+    pub listed: Option<NaiveDate>,
+}
+
+/// 合并沪深京股票列表，跳过代码不合法的行
+pub(crate) fn listings(sh: Vec<StockInfo>, sz: Vec<StockInfo>, bj: Vec<StockInfo>) -> Vec<Listing> {
+    let lists = [(Exchange::SH, sh), (Exchange::SZ, sz), (Exchange::BJ, bj)];
+    let mut data = Vec::new();
+    for (exchange, list) in lists {
+        for info in list {
+            match Symbol::new(&info.code, exchange) {
+                Ok(symbol) => data.push(Listing {
+                    symbol,
+                    listed: info.listing_date(),
+                    name: info.name,
+                }),
+                Err(e) => tracing::warn!("skip {} listing: {}", exchange, e),
+            }
+        }
+    }
+    data
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct StockCode {
     symbol: String,
 }
 
+impl StockCode {
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+}
+
 impl From<&str> for StockCode {
     fn from(s: &str) -> Self {
         Self {
@@ -71,7 +265,7 @@ impl From<&str> for StockCode {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Quarter {
     Q1,
     Q2,
@@ -97,7 +291,25 @@ impl From<NaiveDate> for Quarter {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+/// 支持 `Q1`、`1`、`一季报`/`中报`/`三季报`/`年报` 以及 `一季度`…`四季度`
+impl FromStr for Quarter {
+    type Err = RedCrabError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "Q1" | "1" | "一季报" | "一季度" => Ok(Quarter::Q1),
+            "Q2" | "2" | "中报" | "半年报" | "二季度" => Ok(Quarter::Q2),
+            "Q3" | "3" | "三季报" | "三季度" => Ok(Quarter::Q3),
+            "Q4" | "4" | "年报" | "四季度" => Ok(Quarter::Q4),
+            _ => Err(RedCrabError::InvalidParameter(format!(
+                "invalid quarter: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportDate {
     pub year: i32,
@@ -111,14 +323,147 @@ impl ReportDate {
             quarter: q,
         }
     }
+
+    /// 报告期末日期，如 `2022-03-31`
+    pub fn end_date(&self) -> NaiveDate {
+        let (month, day) = match self.quarter {
+            Quarter::Q1 => (3, 31),
+            Quarter::Q2 => (6, 30),
+            Quarter::Q3 => (9, 30),
+            Quarter::Q4 => (12, 31),
+        };
+        NaiveDate::from_ymd_opt(self.year, month, day).unwrap()
+    }
+
+    /// 上一个季度，一季度的上一个季度为上年四季度
+    pub fn previous_quarter(&self) -> ReportDate {
+        Self::from_ordinal(self.ordinal() - 1)
+    }
+
+    /// 下一个季度
+    pub fn next_quarter(&self) -> ReportDate {
+        Self::from_ordinal(self.ordinal() + 1)
+    }
+
+    /// 上年同期
+    pub fn year_ago(&self) -> ReportDate {
+        ReportDate::new(self.year - 1, self.quarter)
+    }
+
+    /// 从 `self` 到 `other` 相隔的季度数，`other` 更早时为负数
+    pub fn quarters_between(&self, other: &ReportDate) -> i32 {
+        other.ordinal() - self.ordinal()
+    }
+
+    /// `from` 到 `to`（含）之间的全部报告期，`from` 晚于 `to` 时为空
+    pub fn range(from: &ReportDate, to: &ReportDate) -> ReportDateRange {
+        ReportDateRange {
+            next: from.ordinal(),
+            end: to.ordinal(),
+        }
+    }
+
+    fn ordinal(&self) -> i32 {
+        let q = match self.quarter {
+            Quarter::Q1 => 0,
+            Quarter::Q2 => 1,
+            Quarter::Q3 => 2,
+            Quarter::Q4 => 3,
+        };
+        self.year * 4 + q
+    }
+
+    fn from_ordinal(n: i32) -> ReportDate {
+        let quarter = match n.rem_euclid(4) {
+            0 => Quarter::Q1,
+            1 => Quarter::Q2,
+            2 => Quarter::Q3,
+            _ => Quarter::Q4,
+        };
+        ReportDate::new(n.div_euclid(4), quarter)
+    }
 }
 
-impl From<NaiveDate> for ReportDate {
-    fn from(s: NaiveDate) -> Self {
-        ReportDate {
-            year: s.year(),
-            quarter: s.into(),
+/// [`ReportDate::range`] 返回的迭代器
+#[derive(Debug, Clone)]
+pub struct ReportDateRange {
+    next: i32,
+    end: i32,
+}
+
+impl Iterator for ReportDateRange {
+    type Item = ReportDate;
+
+    fn next(&mut self) -> Option<ReportDate> {
+        if self.next > self.end {
+            return None;
+        }
+        let date = ReportDate::from_ordinal(self.next);
+        self.next += 1;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.next + 1).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ReportDateRange {
+    fn next_back(&mut self) -> Option<ReportDate> {
+        if self.next > self.end {
+            return None;
+        }
+        let date = ReportDate::from_ordinal(self.end);
+        self.end -= 1;
+        Some(date)
+    }
+}
+
+impl ExactSizeIterator for ReportDateRange {}
+
+/// 只接受季末日期：03-31、06-30、09-30、12-31
+impl TryFrom<NaiveDate> for ReportDate {
+    type Error = RedCrabError;
+
+    fn try_from(s: NaiveDate) -> Result<Self> {
+        match (s.month(), s.day()) {
+            (3, 31) | (6, 30) | (9, 30) | (12, 31) => Ok(ReportDate {
+                year: s.year(),
+                quarter: s.into(),
+            }),
+            _ => Err(RedCrabError::InvalidParameter(format!(
+                "{} is not a quarter end",
+                s
+            ))),
+        }
+    }
+}
+
+/// 支持 `2022Q1`、`2022-03-31`、`2022-03-31 00:00:00`、`20220331`
+/// 以及 `REPORT_DATE_NAME` 格式，如 `2022一季报`、`2022中报`、`2022年报`
+impl FromStr for ReportDate {
+    type Err = RedCrabError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Ok(t) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            return t.date().try_into();
         }
+        for fmt in ["%Y-%m-%d", "%Y%m%d"] {
+            if let Ok(t) = NaiveDate::parse_from_str(s, fmt) {
+                return t.try_into();
+            }
+        }
+
+        let invalid = || RedCrabError::InvalidParameter(format!("invalid report date: {}", s));
+        let (year, quarter) = match (s.get(..4), s.get(4..)) {
+            (Some(year), Some(quarter)) => (year, quarter.trim_start_matches(['-', ' '])),
+            _ => return Err(invalid()),
+        };
+        let year = year.parse().map_err(|_| invalid())?;
+        let quarter = quarter.parse().map_err(|_| invalid())?;
+        Ok(ReportDate::new(year, quarter))
     }
 }
 
@@ -134,26 +479,155 @@ impl fmt::Display for ReportDate {
     }
 }
 
+/// 报表口径
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum StatementPeriod {
+    /// 按报告期，利润表与现金流量表为年初至今累计值
+    #[default]
+    Report,
+    /// 按年度
+    Yearly,
+    /// 按单季度，资产负债表没有该口径
+    Quarterly,
+}
+
+impl StatementPeriod {
+    /// 拼接接口名，如 `stock_profit_sheet` -> `stock_profit_sheet_by_quarterly_em`
+    pub(crate) fn endpoint(&self, sheet: &str) -> String {
+        let period = match self {
+            StatementPeriod::Report => "report",
+            StatementPeriod::Yearly => "yearly",
+            StatementPeriod::Quarterly => "quarterly",
+        };
+        format!("{}_by_{}_em", sheet, period)
+    }
+}
+
+/// 回放 `tests/fixtures/akshare` 中录制数据的客户端
+#[cfg(test)]
+pub(crate) fn fixture_client() -> Akshare {
+    Akshare::builder()
+        .backend(Backend::Replay(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/akshare").into(),
+        ))
+        .build()
+        .unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn report_date_works() {
+        let q1 = ReportDate::new(2022, Quarter::Q1);
+        assert_eq!(q1.previous_quarter(), ReportDate::new(2021, Quarter::Q4));
+        assert_eq!(q1.previous_quarter().next_quarter(), q1);
+        assert_eq!(q1.year_ago(), ReportDate::new(2021, Quarter::Q1));
+        assert!(q1.year_ago() < q1.previous_quarter());
+        assert_eq!(q1.end_date(), NaiveDate::from_ymd_opt(2022, 3, 31).unwrap());
+
+        let q3 = ReportDate::new(2023, Quarter::Q3);
+        assert_eq!(q1.quarters_between(&q3), 6);
+        assert_eq!(q3.quarters_between(&q1), -6);
+
+        let range: Vec<ReportDate> = ReportDate::range(&q1, &q3).collect();
+        assert_eq!(range.len(), 7);
+        assert_eq!(range[3], ReportDate::new(2022, Quarter::Q4));
+        assert_eq!(range.last(), Some(&q3));
+        assert!(range.windows(2).all(|x| x[0] < x[1]));
+        assert_eq!(ReportDate::range(&q1, &q3).next_back(), Some(q3.clone()));
+        assert_eq!(ReportDate::range(&q3, &q1).len(), 0);
+    }
+
+    #[test]
+    fn parse_works() {
+        let q1 = ReportDate::new(2022, Quarter::Q1);
+        for s in [
+            "2022Q1",
+            "2022q1",
+            "2022-Q1",
+            "2022-03-31",
+            "20220331",
+            "2022一季报",
+        ] {
+            assert_eq!(s.parse::<ReportDate>().unwrap(), q1, "{}", s);
+        }
+        assert_eq!(
+            "2021-12-31 00:00:00".parse::<ReportDate>().unwrap(),
+            ReportDate::new(2021, Quarter::Q4)
+        );
+        assert_eq!(
+            "2022中报".parse::<ReportDate>().unwrap(),
+            ReportDate::new(2022, Quarter::Q2)
+        );
+        assert_eq!(
+            "2021年报".parse::<ReportDate>().unwrap(),
+            ReportDate::new(2021, Quarter::Q4)
+        );
+        assert_eq!(q1.to_string().parse::<ReportDate>().unwrap(), q1);
+        for s in ["2022-03-30", "20220401", "2022Q5", "2022", "中报", ""] {
+            assert!(s.parse::<ReportDate>().is_err(), "{}", s);
+        }
+
+        assert_eq!("三季报".parse::<Quarter>().unwrap(), Quarter::Q3);
+        assert_eq!("q4".parse::<Quarter>().unwrap(), Quarter::Q4);
+
+        let date = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        assert_eq!(
+            ReportDate::try_from(date).unwrap(),
+            ReportDate::new(2022, Quarter::Q3)
+        );
+        let date = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        assert!(ReportDate::try_from(date).is_err());
+    }
+
+    #[test]
+    fn builder_works() {
+        let akshare = Akshare::builder()
+            .base_url("http://10.0.0.1:8080/api/public/")
+            .header("x-auth-token", "secret")
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+        assert_eq!(akshare.base_url(), "http://10.0.0.1:8080/api/public/");
+        assert_eq!(Akshare::new().unwrap().base_url(), DEFAULT_BASE_URL);
+    }
+
     #[tokio::test]
     async fn sh_list_works() {
-        let akshare = Akshare::new();
-        if let Ok(client) = akshare {
-            let s = client.sh_list().await.unwrap();
-            print!("{:?}", s);
-        }
+        let s = fixture_client().sh_list().await.unwrap();
+        assert_eq!(s.len(), 3);
+        assert_eq!(s[1].code, "600519");
+        assert_eq!(s[1].name, "贵州茅台");
+        assert_eq!(s[1].time, "2001-08-27");
     }
 
     #[tokio::test]
     async fn sz_list_works() {
-        let akshare = Akshare::new();
-        if let Ok(client) = akshare {
-            let s = client.sz_list().await.unwrap();
-            print!("{:?}", s);
-        }
+        let s = fixture_client().sz_list().await.unwrap();
+        assert_eq!(s.len(), 3);
+        assert_eq!(s[1].code, "002027");
+        assert_eq!(s[1].name, "分众传媒");
+        assert_eq!(s[1].time, "2004-08-04");
+    }
+
+    #[tokio::test]
+    async fn all_a_shares_works() {
+        let client = fixture_client();
+        let s = client.bj_list().await.unwrap();
+        assert_eq!(s[1].code, "830799");
+        assert_eq!(s[1].name, "艾融软件");
+
+        let s = client.all_a_shares().await.unwrap();
+        assert_eq!(s.len(), 8);
+        let bj = s.last().unwrap();
+        assert_eq!(bj.symbol.prefixed(), "BJ830799");
+        assert_eq!(bj.symbol.board(), Board::Bse);
+        assert_eq!(bj.listed, NaiveDate::from_ymd_opt(2021, 11, 15));
+        let moutai = s.iter().find(|x| x.symbol.code() == "600519").unwrap();
+        assert_eq!(moutai.symbol.exchange(), Exchange::SH);
+        assert_eq!(moutai.listed, NaiveDate::from_ymd_opt(2001, 8, 27));
     }
 
     #[test]
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::Quarter;
+use crate::akshare::Quarter;
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -8,6 +8,7 @@ pub struct DateReq {
     pub date: String,
 }
 
+#[allow(dead_code)]
 impl DateReq {
     fn from_str(year: &str, q: &Quarter) -> DateReq {
         match q {
@@ -0,0 +1,168 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use rand::Rng;
+
+use crate::errors::RedCrabError;
+
+type Retryable = Arc<dyn Fn(&RedCrabError) -> bool + Send + Sync>;
+
+/// 请求重试策略：指数退避 + 随机抖动
+///
+/// 第 n 次失败后等待 `min(base_delay * 2^(n-1), max_delay)`，开启抖动时在 `[0, 该值]` 内随机取值。
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（包含第一次请求），1 表示不重试
+    pub max_attempts: u32,
+    /// 退避基数
+    pub base_delay: Duration,
+    /// 退避上限
+    pub max_delay: Duration,
+    /// 是否随机抖动
+    pub jitter: bool,
+    retryable: Retryable,
+}
+
+impl RetryPolicy {
+    /// 不重试
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// 自定义哪些错误需要重试
+    pub fn retry_if<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RedCrabError) -> bool + Send + Sync + 'static,
+    {
+        self.retryable = Arc::new(f);
+        self
+    }
+
+    pub fn is_retryable(&self, error: &RedCrabError) -> bool {
+        (self.retryable)(error)
+    }
+
+    /// 第 `attempt` 次请求返回 `error` 后是否重试，重试时返回等待时间
+    pub fn retry_delay(&self, attempt: u32, error: &RedCrabError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+        let delay = self.delay(attempt);
+        tracing::debug!(
+            "request failed ({}), retry #{} in {:?}",
+            error,
+            attempt,
+            delay
+        );
+        Some(delay)
+    }
+
+    /// 第 `attempt` 次失败后的等待时间（`attempt` 从 1 开始）
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31);
+        let delay = self.base_delay.saturating_mul(1 << exp).min(self.max_delay);
+        if self.jitter && !delay.is_zero() {
+            rand::thread_rng().gen_range(Duration::ZERO..=delay)
+        } else {
+            delay
+        }
+    }
+}
+
+/// 默认重试 500/503、连接失败、超时和连接被重置
+pub fn default_retryable(error: &RedCrabError) -> bool {
+    match error.root() {
+        RedCrabError::InternalServerError() | RedCrabError::ServiceUnavailable() => true,
+        RedCrabError::ReqError(e) => {
+            e.is_connect() || e.is_timeout() || e.is_request() || e.is_body()
+        }
+        _ => false,
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+            retryable: Arc::new(default_retryable),
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_works() {
+        let policy = RetryPolicy::default()
+            .backoff(Duration::from_millis(100), Duration::from_millis(500))
+            .jitter(false);
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(4), Duration::from_millis(500));
+        assert_eq!(policy.delay(64), Duration::from_millis(500));
+
+        let policy = policy.jitter(true);
+        for attempt in 1..10 {
+            assert!(policy.delay(attempt) <= Duration::from_millis(500));
+        }
+    }
+
+    #[test]
+    fn retryable_works() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable(&RedCrabError::InternalServerError()));
+        assert!(policy.is_retryable(&RedCrabError::ServiceUnavailable()));
+        assert!(!policy.is_retryable(&RedCrabError::Unauthorized()));
+        assert!(!policy.is_retryable(&RedCrabError::InvalidParameter("".into())));
+        let shared = RedCrabError::Shared(Arc::new(RedCrabError::ServiceUnavailable()));
+        assert!(policy.is_retryable(&shared));
+
+        assert!(policy
+            .retry_delay(2, &RedCrabError::InternalServerError())
+            .is_some());
+        assert_eq!(
+            policy.retry_delay(3, &RedCrabError::InternalServerError()),
+            None
+        );
+        assert_eq!(policy.retry_delay(1, &RedCrabError::Unauthorized()), None);
+
+        let policy = policy.retry_if(|e| matches!(e, RedCrabError::Unauthorized()));
+        assert!(policy.is_retryable(&RedCrabError::Unauthorized()));
+        assert!(!policy.is_retryable(&RedCrabError::InternalServerError()));
+    }
+}
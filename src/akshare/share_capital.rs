@@ -0,0 +1,112 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+use super::{shared::date_from_string, Akshare, StockCode, Symbol};
+
+impl Akshare {
+    /// 股本结构变动历史
+    pub async fn get_share_changes(&self, symbol: &Symbol) -> Result<ShareHistory> {
+        let data = self
+            .transport
+            .get(
+                "stock_zh_a_gbjg_em",
+                Some(&StockCode::from(symbol.suffixed().as_str())),
+            )
+            .await?;
+        Ok(ShareHistory::new(data))
+    }
+}
+
+/// 一次股本变动后的股本结构（股）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct ShareChange {
+    #[serde(alias = "变更日期", with = "date_from_string")]
+    pub date: NaiveDate,
+    /// 总股本，与资产负债表 `SHARE_CAPITAL` 一致
+    #[serde(alias = "总股本")]
+    pub total_shares: f64,
+    #[serde(alias = "流通受限股份")]
+    pub restricted_shares: Option<f64>,
+    #[serde(alias = "已流通股份")]
+    pub float_shares: Option<f64>,
+    #[serde(alias = "已上市流通A股")]
+    pub float_a_shares: Option<f64>,
+    #[serde(alias = "变动原因")]
+    pub reason: Option<String>,
+}
+
+/// 按变更日期升序排列的股本变动
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct ShareHistory {
+    changes: Vec<ShareChange>,
+}
+
+impl ShareHistory {
+    pub fn new(mut changes: Vec<ShareChange>) -> Self {
+        changes.sort_by_key(|x| x.date);
+        Self { changes }
+    }
+
+    pub fn changes(&self) -> &[ShareChange] {
+        &self.changes
+    }
+
+    /// `date`（含）当天生效的股本结构，早于第一次变动时为 `None`
+    pub fn at(&self, date: NaiveDate) -> Option<&ShareChange> {
+        let n = self.changes.partition_point(|x| x.date <= date);
+        n.checked_sub(1).map(|i| &self.changes[i])
+    }
+
+    /// `date` 的总股本
+    pub fn shares_outstanding_at(&self, date: NaiveDate) -> Option<f64> {
+        self.at(date).map(|x| x.total_shares)
+    }
+
+    /// `date` 的已流通股份
+    pub fn float_shares_at(&self, date: NaiveDate) -> Option<f64> {
+        self.at(date).and_then(|x| x.float_shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::fixture_client;
+
+    #[tokio::test]
+    async fn it_works() {
+        let client = fixture_client();
+        let symbol: Symbol = "SZ002027".parse().unwrap();
+        let history = client.get_share_changes(&symbol).await.unwrap();
+        assert_eq!(history.changes().len(), 3);
+        assert_eq!(
+            history.changes()[0].date,
+            NaiveDate::from_ymd_opt(2018, 6, 15).unwrap()
+        );
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(history.shares_outstanding_at(date(2018, 6, 14)), None);
+        assert_eq!(
+            history.shares_outstanding_at(date(2018, 6, 15)),
+            Some(14677880280.0)
+        );
+        assert_eq!(
+            history.float_shares_at(date(2021, 1, 1)),
+            Some(14058857690.0)
+        );
+        assert_eq!(
+            history.float_shares_at(date(2022, 1, 1)),
+            Some(14442199726.0)
+        );
+
+        let balance = client.get_balance_sheet(&(&symbol).into()).await.unwrap();
+        for (report_date, data) in balance.iter() {
+            assert_eq!(
+                history.shares_outstanding_at(report_date.end_date()),
+                data.share_capital
+            );
+        }
+    }
+}
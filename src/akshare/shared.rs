@@ -63,7 +63,6 @@ pub mod string_to_opt_decimal {
 pub mod report_date_from_string {
     use std::fmt;
 
-    use chrono::naive::NaiveDateTime;
     use serde::{Deserialize, Deserializer, Serializer};
 
     use crate::akshare::ReportDate;
@@ -81,23 +80,92 @@ pub mod report_date_from_string {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let a = NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S");
-
-        match a {
-            Ok(t) => Ok(t.date().into()),
-            Err(e) => {
-                let ss = NaiveDateTime::parse_from_str(&s, "%Y-%m-%d")
-                    .map_err(serde::de::Error::custom)?;
-                Ok(ss.date().into())
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// `NaiveDate` 与 `2022-09-01`、`2022-09-01 00:00:00`、`2022-09-01T00:00:00.000` 互转
+pub mod date_from_string {
+    use chrono::NaiveDate;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let date = s.get(..10).unwrap_or(&s);
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(serde::de::Error::custom)
+    }
+}
+
+/// 同 [`date_from_string`]，空值为 `None`
+pub mod opt_date_from_string {
+    use chrono::NaiveDate;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<NaiveDate>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(value) = value {
+            return serializer.collect_str(value);
+        }
+        serializer.serialize_none()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = Option::<String>::deserialize(deserializer)?;
+        match s.as_deref().map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(s) => {
+                let date = s.get(..10).unwrap_or(s);
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .map(Some)
+                    .map_err(serde::de::Error::custom)
             }
         }
     }
 }
 
+/// 只有 `symbol` 一个参数的查询，用于 `symbol` 不是股票代码的接口，如退市类别、板块名称
+#[derive(serde::Serialize, Debug)]
+pub(crate) struct SymbolQuery<'a> {
+    symbol: &'a str,
+}
+
+impl<'a> SymbolQuery<'a> {
+    pub(crate) fn new(symbol: &'a str) -> Self {
+        Self { symbol }
+    }
+}
+
+/// 带报告期的报表数据
+pub trait ReportDated {
+    fn report_date(&self) -> &crate::akshare::ReportDate;
+}
+
+/// 按报告期索引，同一报告期保留最后一条
+pub(crate) fn by_report_date<T: ReportDated>(
+    data: Vec<T>,
+) -> std::collections::BTreeMap<crate::akshare::ReportDate, T> {
+    data.into_iter()
+        .map(|x| (x.report_date().to_owned(), x))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn sh_list_works() {
         let date =
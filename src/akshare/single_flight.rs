@@ -0,0 +1,179 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use futures::{
+    future::{BoxFuture, Shared},
+    FutureExt, TryFutureExt,
+};
+
+use crate::errors::{RedCrabError, Result};
+
+use super::cache::CacheKey;
+
+type Fetch = Shared<BoxFuture<'static, std::result::Result<Arc<str>, Arc<RedCrabError>>>>;
+
+/// 合并并发的相同请求：同一 (接口名, 查询参数) 同时只发起一次请求，结果分发给所有调用方
+#[derive(Clone, Default)]
+pub struct SingleFlight {
+    pending: Arc<Mutex<HashMap<CacheKey, Fetch>>>,
+}
+
+impl SingleFlight {
+    /// 已有相同请求在进行时等待其结果，否则执行 `fetch`
+    ///
+    /// 失败时最后一个拿到结果的调用方取得原始错误，其余调用方得到它的副本；
+    /// 无法复制的错误包装为 [`RedCrabError::Shared`]。
+    pub async fn run<F>(&self, key: CacheKey, fetch: F) -> Result<Arc<str>>
+    where
+        F: Future<Output = Result<Arc<str>>> + Send + 'static,
+    {
+        let fetch = self
+            .lock()
+            .entry(key.clone())
+            .or_insert_with(|| fetch.map_err(Arc::new).boxed().shared())
+            .clone();
+        let handle = fetch.clone();
+        let result = fetch.await;
+
+        {
+            let mut pending = self.lock();
+            if pending.get(&key).is_some_and(|x| x.ptr_eq(&handle)) {
+                pending.remove(&key);
+            }
+        }
+        drop(handle);
+
+        result.map_err(unshare)
+    }
+
+    /// 正在进行的请求数
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<CacheKey, Fetch>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn unshare(e: Arc<RedCrabError>) -> RedCrabError {
+    match Arc::try_unwrap(e) {
+        Ok(e) => e,
+        Err(e) => e.try_clone().unwrap_or(RedCrabError::Shared(e)),
+    }
+}
+
+impl fmt::Debug for SingleFlight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SingleFlight")
+            .field("pending", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::future::join_all;
+
+    use super::*;
+    use crate::akshare::{
+        mock::{Fault, MockServer},
+        retry::RetryPolicy,
+        Akshare,
+    };
+
+    async fn setup() -> (MockServer, Akshare) {
+        let server = MockServer::with_fixtures(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/akshare"
+        ))
+        .await
+        .unwrap();
+        let client = Akshare::builder()
+            .base_url(&server.base_url())
+            .retry_policy(RetryPolicy::none())
+            .cache(None)
+            .build()
+            .unwrap();
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn coalescing_works() {
+        let (server, client) = setup().await;
+        let point = "stock_balance_sheet_by_report_em";
+        server.fail(point, Fault::Delay(Duration::from_millis(50)), 1);
+
+        let code = "SZ002027".into();
+        let results = join_all((0..10).map(|_| client.get_balance_sheet(&code))).await;
+        assert!(results.iter().all(|x| x.as_ref().unwrap().len() == 7));
+        assert_eq!(server.hits(point), 1);
+        assert!(client.transport.single_flight().is_empty());
+
+        client.get_balance_sheet(&code).await.unwrap();
+        assert_eq!(server.hits(point), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_are_shared() {
+        let (server, client) = setup().await;
+        let point = "stock_profit_sheet_by_report_em";
+        server.fail(point, Fault::Status(500), 1);
+
+        let code = "SZ002027".into();
+        let results = join_all((0..5).map(|_| client.get_income_statement(&code))).await;
+        for result in results {
+            let err = result.unwrap_err();
+            assert!(matches!(err, RedCrabError::InternalServerError()));
+        }
+        assert_eq!(server.hits(point), 1);
+
+        server.fail(point, Fault::Status(503), 1);
+        let err = client.get_income_statement(&code).await.unwrap_err();
+        assert!(matches!(err, RedCrabError::ServiceUnavailable()));
+    }
+
+    #[tokio::test]
+    async fn error_shape_is_stable() {
+        let (server, client) = setup().await;
+        let point = "stock_cash_flow_sheet_by_report_em";
+        server.fail(point, Fault::Status(503), 1);
+
+        let code = "SZ002027".into();
+        let (a, b) = futures::join!(
+            client.get_cash_flow_statement(&code),
+            client.get_cash_flow_statement(&code)
+        );
+        let (a, b) = (a.unwrap_err(), b.unwrap_err());
+        assert_eq!(server.hits(point), 1);
+        assert!(matches!(a, RedCrabError::ServiceUnavailable()));
+        assert!(matches!(b, RedCrabError::ServiceUnavailable()));
+    }
+
+    #[test]
+    fn unshare_works() {
+        let e = unshare(Arc::new(RedCrabError::InternalServerError()));
+        assert!(matches!(e, RedCrabError::InternalServerError()));
+
+        let shared = Arc::new(RedCrabError::InvalidParameter("bad".to_owned()));
+        let _other = shared.clone();
+        assert!(matches!(unshare(shared), RedCrabError::InvalidParameter(x) if x == "bad"));
+
+        let io = Arc::new(RedCrabError::IoError(std::io::ErrorKind::Other.into()));
+        let _other = io.clone();
+        match unshare(io) {
+            RedCrabError::Shared(e) => assert!(matches!(*e, RedCrabError::IoError(_))),
+            e => panic!("unexpected {:?}", e),
+        }
+    }
+}
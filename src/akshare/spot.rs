@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+use super::{Akshare, StockCode, Symbol};
+
+impl Akshare {
+    /// 沪深京 A 股实时行情，停牌股票的价格等字段为 `None`；默认不经过响应缓存
+    pub async fn spot_quotes(&self) -> Result<BTreeMap<Symbol, Quote>> {
+        let data: Vec<Quote> = self
+            .transport
+            .get("stock_zh_a_spot_em", None::<&StockCode>)
+            .await?;
+        Ok(by_symbol(data))
+    }
+}
+
+/// 按股票代码索引，跳过无法识别交易所的代码
+pub(crate) fn by_symbol(data: Vec<Quote>) -> BTreeMap<Symbol, Quote> {
+    let mut map = BTreeMap::new();
+    for d in data {
+        match d.code.parse::<Symbol>() {
+            Ok(symbol) => {
+                map.insert(symbol, d);
+            }
+            Err(e) => tracing::warn!("skip spot quote: {}", e),
+        }
+    }
+    map
+}
+
+/// 实时行情
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Quote {
+    #[serde(alias = "代码")]
+    pub code: String,
+    #[serde(alias = "名称")]
+    pub name: String,
+    /// 最新价
+    #[serde(alias = "最新价")]
+    pub price: Option<f64>,
+    /// 涨跌幅（%）
+    #[serde(alias = "涨跌幅")]
+    pub change_pct: Option<f64>,
+    /// 涨跌额
+    #[serde(alias = "涨跌额")]
+    pub change: Option<f64>,
+    /// 成交量（手）
+    #[serde(alias = "成交量")]
+    pub volume: Option<f64>,
+    /// 成交额（元）
+    #[serde(alias = "成交额")]
+    pub amount: Option<f64>,
+    #[serde(alias = "今开")]
+    pub open: Option<f64>,
+    #[serde(alias = "最高")]
+    pub high: Option<f64>,
+    #[serde(alias = "最低")]
+    pub low: Option<f64>,
+    #[serde(alias = "昨收")]
+    pub prev_close: Option<f64>,
+    /// 换手率（%）
+    #[serde(alias = "换手率")]
+    pub turnover: Option<f64>,
+    /// 动态市盈率
+    #[serde(alias = "市盈率-动态")]
+    pub pe_dynamic: Option<f64>,
+    /// 市净率
+    #[serde(alias = "市净率")]
+    pub pb: Option<f64>,
+    /// 总市值（元）
+    #[serde(alias = "总市值")]
+    pub total_market_cap: Option<f64>,
+    /// 流通市值（元）
+    #[serde(alias = "流通市值")]
+    pub float_market_cap: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::akshare::{
+        fixture_client, mock::MockServer, retry::RetryPolicy, Akshare, Exchange, Symbol,
+    };
+
+    #[tokio::test]
+    async fn it_works() {
+        let quotes = fixture_client().spot_quotes().await.unwrap();
+        assert_eq!(quotes.len(), 5);
+
+        let q = &quotes[&"SZ002027".parse::<Symbol>().unwrap()];
+        assert_eq!(q.name, "分众传媒");
+        assert_eq!(q.price, Some(6.07));
+        assert_eq!(q.pe_dynamic, Some(27.61));
+        assert_eq!(q.pb, Some(3.85));
+        assert_eq!(q.total_market_cap, Some(87664151336.82));
+
+        let (bj, _) = quotes.iter().find(|(_, x)| x.code == "830799").unwrap();
+        assert_eq!(bj.exchange(), Exchange::BJ);
+
+        let suspended = &quotes[&"SH600001".parse::<Symbol>().unwrap()];
+        assert_eq!(suspended.price, None);
+        assert_eq!(suspended.prev_close, Some(4.82));
+    }
+
+    #[tokio::test]
+    async fn quotes_are_not_cached() {
+        let server = MockServer::with_fixtures(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/akshare"
+        ))
+        .await
+        .unwrap();
+        let client = Akshare::builder()
+            .base_url(&server.base_url())
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        client.spot_quotes().await.unwrap();
+        client.spot_quotes().await.unwrap();
+        assert_eq!(server.hits("stock_zh_a_spot_em"), 2);
+
+        client.industry_constituents("银行").await.unwrap();
+        client.industry_constituents("银行").await.unwrap();
+        assert_eq!(server.hits("stock_board_industry_cons_em"), 2);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{RedCrabError, Result};
+
+use super::StockCode;
+
+/// 交易所
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Exchange {
+    /// 上海证券交易所
+    SH,
+    /// 深圳证券交易所
+    SZ,
+    /// 北京证券交易所
+    BJ,
+}
+
+impl Exchange {
+    /// 由 6 位代码推断交易所
+    pub fn infer(code: &str) -> Option<Exchange> {
+        match code.get(..3)? {
+            "600" | "601" | "603" | "605" | "688" | "689" | "900" => Some(Exchange::SH),
+            "000" | "001" | "002" | "003" | "200" | "300" | "301" => Some(Exchange::SZ),
+            "920" => Some(Exchange::BJ),
+            _ => match code.get(..2)? {
+                "43" | "82" | "83" | "87" | "88" => Some(Exchange::BJ),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Exchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Exchange {
+    type Err = RedCrabError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "SH" => Ok(Exchange::SH),
+            "SZ" => Ok(Exchange::SZ),
+            "BJ" => Ok(Exchange::BJ),
+            _ => Err(RedCrabError::InvalidParameter(format!(
+                "invalid exchange: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 板块
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Board {
+    /// 沪深主板
+    Main,
+    /// 创业板
+    ChiNext,
+    /// 科创板
+    Star,
+    /// 北交所
+    Bse,
+}
+
+/// 校验过的 A 股代码，可以解析 `002027`、`SZ002027`（或 `sz002027`）、`002027.SZ` 三种写法，
+/// 写明的交易所须与代码所属交易所一致
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Symbol {
+    exchange: Exchange,
+    code: String,
+}
+
+impl Symbol {
+    /// `exchange` 须与代码所属交易所（[`Exchange::infer`]）一致
+    pub fn new(code: &str, exchange: Exchange) -> Result<Self> {
+        if code.len() != 6
+            || !code.bytes().all(|x| x.is_ascii_digit())
+            || Exchange::infer(code) != Some(exchange)
+        {
+            return Err(RedCrabError::InvalidParameter(format!(
+                "invalid stock code: {}.{}",
+                code, exchange
+            )));
+        }
+        Ok(Self {
+            exchange,
+            code: code.to_owned(),
+        })
+    }
+
+    /// 6 位代码，如 `002027`，与 `StockInfo.code` 一致
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn exchange(&self) -> Exchange {
+        self.exchange
+    }
+
+    pub fn board(&self) -> Board {
+        match (self.exchange, &self.code[..3]) {
+            (Exchange::BJ, _) => Board::Bse,
+            (Exchange::SH, "688" | "689") => Board::Star,
+            (Exchange::SZ, "300" | "301") => Board::ChiNext,
+            _ => Board::Main,
+        }
+    }
+
+    /// 前缀写法，如 `SZ002027`，报表类接口使用
+    pub fn prefixed(&self) -> String {
+        format!("{}{}", self.exchange, self.code)
+    }
+
+    /// 后缀写法，如 `002027.SZ`，与 `SECUCODE` 一致
+    pub fn suffixed(&self) -> String {
+        format!("{}.{}", self.code, self.exchange)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.exchange, self.code)
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = RedCrabError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let invalid = || RedCrabError::InvalidParameter(format!("invalid symbol: {}", s));
+
+        let (code, exchange) = if let Some((code, exchange)) = s.split_once('.') {
+            (code, Some(exchange.parse::<Exchange>()?))
+        } else if s.len() == 8 {
+            let (exchange, code) = s.split_at_checked(2).ok_or_else(invalid)?;
+            (code, Some(exchange.parse::<Exchange>()?))
+        } else {
+            (s, None)
+        };
+        // 写明的交易所由 `Symbol::new` 校验是否与代码一致
+        let exchange = match exchange {
+            Some(exchange) => exchange,
+            None => Exchange::infer(code).ok_or_else(invalid)?,
+        };
+        Symbol::new(code, exchange).map_err(|_| invalid())
+    }
+}
+
+impl TryFrom<String> for Symbol {
+    type Error = RedCrabError;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(s: Symbol) -> Self {
+        s.to_string()
+    }
+}
+
+impl TryFrom<&StockCode> for Symbol {
+    type Error = RedCrabError;
+
+    fn try_from(s: &StockCode) -> Result<Self> {
+        s.symbol().parse()
+    }
+}
+
+impl From<&Symbol> for StockCode {
+    fn from(s: &Symbol) -> Self {
+        s.prefixed().as_str().into()
+    }
+}
+
+impl From<Symbol> for StockCode {
+    fn from(s: Symbol) -> Self {
+        (&s).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let s: Symbol = "SZ002027".parse().unwrap();
+        assert_eq!(s, "002027".parse().unwrap());
+        assert_eq!(s, "002027.SZ".parse().unwrap());
+        assert_eq!(s, "sz002027".parse().unwrap());
+        assert_eq!(s.code(), "002027");
+        assert_eq!(s.exchange(), Exchange::SZ);
+        assert_eq!(s.board(), Board::Main);
+        assert_eq!(s.prefixed(), "SZ002027");
+        assert_eq!(s.suffixed(), "002027.SZ");
+        assert_eq!(StockCode::from(&s).symbol(), "SZ002027");
+
+        assert_eq!("688981".parse::<Symbol>().unwrap().board(), Board::Star);
+        assert_eq!("300750".parse::<Symbol>().unwrap().board(), Board::ChiNext);
+        let bj: Symbol = "830799".parse().unwrap();
+        assert_eq!((bj.exchange(), bj.board()), (Exchange::BJ, Board::Bse));
+        assert_eq!(bj.suffixed(), "830799.BJ");
+
+        for s in [
+            "",
+            "02027",
+            "SZ02027",
+            "HK002027",
+            "002027.HK",
+            "123456",
+            "SZ00202A",
+            "SZ600519",
+            "600519.SZ",
+            "SH002027",
+            "002027.BJ",
+            "BJ600519",
+            "SH830799",
+        ] {
+            assert!(s.parse::<Symbol>().is_err(), "{}", s);
+        }
+
+        assert!(Symbol::new("600519", Exchange::SH).is_ok());
+        assert!(Symbol::new("600519", Exchange::SZ).is_err());
+        assert!(Symbol::new("830799", Exchange::SH).is_err());
+        assert!(Symbol::new("123456", Exchange::SZ).is_err());
+    }
+
+    #[test]
+    fn serde_works() {
+        let s: Symbol = "600519".parse().unwrap();
+        assert_eq!(serde_json::to_string(&s).unwrap(), "\"SH600519\"");
+        let back: Symbol = serde_json::from_str("\"600519.SH\"").unwrap();
+        assert_eq!(back, s);
+    }
+}
@@ -1,29 +1,114 @@
-use reqwest::{header, Response, StatusCode, Url};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use reqwest::{header, Proxy, RequestBuilder, StatusCode, Url};
 use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::Semaphore;
 
 use crate::errors::{RedCrabError, Result};
 
+use super::{
+    blocking::BlockingTransport,
+    cache::{CacheKey, ResponseCache},
+    fixture::Fixture,
+    limiter::RateLimiter,
+    retry::RetryPolicy,
+    single_flight::SingleFlight,
+};
+
+/// 默认的 akshare 服务地址
+pub const DEFAULT_BASE_URL: &str = "http://127.0.0.1:8080/api/public/";
+
+/// 默认最多同时发起的请求数
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 4;
+
+/// 请求的实际去向
+#[derive(Clone, Debug, Default)]
+pub enum Backend {
+    /// 请求 HTTP 服务
+    #[default]
+    Http,
+    /// 请求 HTTP 服务，并把每次响应录制到目录
+    Record(PathBuf),
+    /// 不发起请求，从目录回放录制的响应
+    Replay(PathBuf),
+}
+
 #[derive(Clone, Debug)]
 pub struct Transport {
     client: reqwest::Client,
     base_url: String,
+    backend: Backend,
+    retry: RetryPolicy,
+    limiter: Option<RateLimiter>,
+    in_flight: Option<Arc<Semaphore>>,
+    cache: Option<ResponseCache>,
+    single_flight: SingleFlight,
 }
 
 impl Transport {
     pub fn new() -> Result<Self> {
-        Self::from("http://127.0.0.1:8080/api/public/")
+        Self::from(DEFAULT_BASE_URL)
     }
 
     pub fn from(url: &str) -> Result<Self> {
-        let default_headers = Self::default_headers();
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
+        Self::builder().base_url(url).build()
+    }
 
-        Ok(Transport {
-            client,
-            base_url: url.to_owned(),
-        })
+    pub fn builder() -> TransportBuilder {
+        TransportBuilder::default()
+    }
+
+    pub fn with_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    pub fn backend(&self) -> &Backend {
+        &self.backend
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// 设置限流器，`None` 表示不限流
+    pub fn with_rate_limiter(mut self, limiter: Option<RateLimiter>) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// 设置最多同时发起的请求数，`None` 表示不限制
+    pub fn with_max_in_flight(mut self, max_in_flight: Option<usize>) -> Self {
+        self.in_flight = max_in_flight.map(|x| Arc::new(Semaphore::new(x.max(1))));
+        self
+    }
+
+    /// 设置响应缓存，`None` 表示不缓存
+    pub fn with_cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+
+    /// 正在进行中的 GET 请求
+    pub fn single_flight(&self) -> &SingleFlight {
+        &self.single_flight
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
     }
 
     pub fn default_headers() -> header::HeaderMap<header::HeaderValue> {
@@ -46,9 +131,26 @@ impl Transport {
         S: Serialize,
     {
         let url = self.get_url(endpoint, params)?;
-        let request = self.client.get(url).send().await?;
+        let key = CacheKey::from_url(&url);
+        if let Some(body) = self.cache.as_ref().and_then(|x| x.get(&key)) {
+            return Ok(serde_json::from_str(&body)?);
+        }
 
-        self.response_handler(request).await
+        let this = self.clone();
+        let cache_key = key.clone();
+        let fetch = async move {
+            let body: Arc<str> = this
+                .execute(&url, || this.client.get(url.clone()))
+                .await?
+                .into();
+            if let Some(cache) = &this.cache {
+                cache.insert(cache_key, body.clone());
+            }
+            Ok(body)
+        };
+        let body = self.single_flight.run(key, fetch).await?;
+
+        Ok(serde_json::from_str(&body)?)
     }
 
     pub async fn post<O, D>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
@@ -57,75 +159,403 @@ impl Transport {
         D: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None)?;
-        let request = self.client.post(url).form(&data).send().await?;
 
-        self.response_handler(request).await
+        let body = self
+            .execute(&url, || self.client.post(url.clone()).form(&data))
+            .await?;
+        Ok(serde_json::from_str(&body)?)
     }
 
     pub fn get_url<Q>(&self, endpoint: &str, params: Option<&Q>) -> Result<Url>
     where
         Q: Serialize,
     {
-        let url = format!("{}{}", self.base_url, endpoint);
-
-        let mut url = Url::parse(&url)?;
-
-        if params.is_some() {
-            let query = serde_urlencoded::to_string(params)?;
-            url.set_query(Some(&query));
-        };
+        endpoint_url(&self.base_url, endpoint, params)
+    }
 
-        Ok(url)
+    async fn execute<F>(&self, url: &Url, request: F) -> Result<String>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        match &self.backend {
+            Backend::Replay(dir) => replay(dir, url),
+            _ => self.fetch(url, &request).await,
+        }
     }
 
-    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    async fn fetch<F>(&self, url: &Url, request: &F) -> Result<String>
     where
-        O: DeserializeOwned,
+        F: Fn() -> RequestBuilder,
     {
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<O>().await?),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(RedCrabError::InternalServerError()),
-            StatusCode::SERVICE_UNAVAILABLE => Err(RedCrabError::ServiceUnavailable()),
-            StatusCode::UNAUTHORIZED => Err(RedCrabError::Unauthorized()),
-            StatusCode::BAD_REQUEST => {
-                let error = response.text().await?;
-
-                Err(RedCrabError::InvalidParameter(error))
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self.send(url, request).await {
+                Err(e) => match self.retry.retry_delay(attempt, &e) {
+                    Some(delay) => tokio::time::sleep(delay).await,
+                    None => return Err(e),
+                },
+                result => return result,
             }
-            s => Err(RedCrabError::UnkownResponse(format!(
-                "Received response: {:?}",
-                s
-            ))),
+        }
+    }
+
+    async fn send<F>(&self, url: &Url, request: &F) -> Result<String>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let _permit = match &self.in_flight {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire()
+                    .await
+                    .map_err(|e| RedCrabError::Generic(Box::new(e)))?,
+            ),
+            None => None,
+        };
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire().await;
+        }
+
+        let response = request().send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+        record(&self.backend, url, status, body)
+    }
+}
+
+/// 拼接接口地址与查询参数
+pub(crate) fn endpoint_url<Q>(base_url: &str, endpoint: &str, params: Option<&Q>) -> Result<Url>
+where
+    Q: Serialize,
+{
+    let url = format!("{}{}", base_url, endpoint);
+
+    let mut url = Url::parse(&url)?;
+
+    if params.is_some() {
+        let query = serde_urlencoded::to_string(params)?;
+        url.set_query(Some(&query));
+    };
+
+    Ok(url)
+}
+
+/// 从录制目录回放响应
+pub(crate) fn replay(dir: &Path, url: &Url) -> Result<String> {
+    let fixture = Fixture::load(dir, url)?;
+    response_handler(fixture.status(), fixture.text())
+}
+
+/// 录制模式下保存响应，再按状态码处理
+pub(crate) fn record(
+    backend: &Backend,
+    url: &Url,
+    status: StatusCode,
+    body: String,
+) -> Result<String> {
+    if let Backend::Record(dir) = backend {
+        Fixture::new(url, status, &body).save(dir)?;
+    }
+    response_handler(status, body)
+}
+
+/// 按状态码把响应转换为响应体或错误
+pub(crate) fn response_handler(status: StatusCode, body: String) -> Result<String> {
+    match status {
+        StatusCode::OK => Ok(body),
+        StatusCode::INTERNAL_SERVER_ERROR => Err(RedCrabError::InternalServerError()),
+        StatusCode::SERVICE_UNAVAILABLE => Err(RedCrabError::ServiceUnavailable()),
+        StatusCode::UNAUTHORIZED => Err(RedCrabError::Unauthorized()),
+        StatusCode::BAD_REQUEST => Err(RedCrabError::InvalidParameter(body)),
+        s => Err(RedCrabError::UnkownResponse(format!(
+            "Received response: {:?}",
+            s
+        ))),
+    }
+}
+
+/// 同步与异步的 `ClientBuilder` 方法同名，用同一套设置构建 HTTP 客户端
+macro_rules! build_client {
+    ($client:ty, $settings:expr) => {{
+        let settings = $settings;
+        let mut builder = <$client>::builder()
+            .default_headers(settings.headers()?)
+            .gzip(settings.gzip);
+        if let Some(timeout) = settings.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = settings.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &settings.proxy {
+            builder = builder.proxy(Proxy::all(proxy)?);
+        }
+        builder.build()?
+    }};
+}
+
+/// [`Transport`] 构建器
+#[derive(Clone, Debug)]
+pub struct TransportBuilder {
+    base_url: String,
+    backend: Backend,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    headers: Vec<(String, String)>,
+    gzip: bool,
+    user_agent: Option<String>,
+    retry: RetryPolicy,
+    limiter: Option<RateLimiter>,
+    max_in_flight: Option<usize>,
+    cache: Option<ResponseCache>,
+}
+
+impl TransportBuilder {
+    pub fn base_url(mut self, url: &str) -> Self {
+        self.base_url = url.to_owned();
+        self
+    }
+
+    /// 请求 HTTP、录制或回放
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// 建立连接的超时时间
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 单次请求的总超时时间，从发起连接到读完响应体，而不是两次读之间的间隔
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// HTTP 代理地址，如 `http://10.0.0.1:3128`
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.proxy = Some(url.to_owned());
+        self
+    }
+
+    /// 附加请求头，如网关的认证 token
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.gzip = enable;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 限流器，`None` 表示不限流
+    pub fn rate_limiter(mut self, limiter: Option<RateLimiter>) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// 最多同时发起的请求数，`None` 表示不限制
+    pub fn max_in_flight(mut self, max_in_flight: Option<usize>) -> Self {
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    /// 响应缓存，`None` 表示不缓存
+    pub fn cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn build(self) -> Result<Transport> {
+        Ok(Transport {
+            client: build_client!(reqwest::Client, &self),
+            base_url: self.base_url,
+            backend: self.backend,
+            retry: self.retry,
+            limiter: self.limiter,
+            in_flight: self
+                .max_in_flight
+                .map(|x| Arc::new(Semaphore::new(x.max(1)))),
+            cache: self.cache,
+            single_flight: SingleFlight::default(),
+        })
+    }
+
+    /// 构建同步客户端，限流、并发上限和响应缓存只对异步客户端生效
+    pub fn build_blocking(self) -> Result<BlockingTransport> {
+        Ok(BlockingTransport::new_with(
+            build_client!(reqwest::blocking::Client, &self),
+            self.base_url,
+            self.backend,
+            self.retry,
+        ))
+    }
+
+    /// 非法的请求头名称或取值都返回 [`RedCrabError::InvalidParameter`]
+    fn headers(&self) -> Result<header::HeaderMap> {
+        let invalid = |name: &str, e: &dyn std::error::Error| {
+            RedCrabError::InvalidParameter(format!("invalid header {:?}: {}", name, e))
+        };
+        let mut headers = Transport::default_headers();
+        if let Some(user_agent) = &self.user_agent {
+            let value = header::HeaderValue::from_str(user_agent)
+                .map_err(|e| invalid(header::USER_AGENT.as_str(), &e))?;
+            headers.insert(header::USER_AGENT, value);
+        }
+        for (name, value) in &self.headers {
+            let key =
+                header::HeaderName::from_bytes(name.as_bytes()).map_err(|e| invalid(name, &e))?;
+            let value = header::HeaderValue::from_str(value).map_err(|e| invalid(name, &e))?;
+            headers.insert(key, value);
+        }
+        Ok(headers)
+    }
+}
+
+impl Default for TransportBuilder {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            backend: Backend::Http,
+            connect_timeout: None,
+            timeout: None,
+            proxy: None,
+            headers: Vec::new(),
+            gzip: true,
+            user_agent: None,
+            retry: RetryPolicy::default(),
+            limiter: Some(RateLimiter::default()),
+            max_in_flight: Some(DEFAULT_MAX_IN_FLIGHT),
+            cache: Some(ResponseCache::default()),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, Instant};
+
     use serde_json::json;
 
     use super::*;
 
+    #[test]
+    fn builder_works() {
+        let tran = Transport::builder()
+            .base_url("http://10.0.0.1:8080/api/public/")
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_secs(10))
+            .proxy("http://10.0.0.2:3128")
+            .header("x-auth-token", "secret")
+            .user_agent("red-crab-test")
+            .max_in_flight(None)
+            .build()
+            .unwrap();
+        assert_eq!(tran.base_url(), "http://10.0.0.1:8080/api/public/");
+        assert!(tran.in_flight.is_none());
+
+        let err = Transport::builder().header("bad header", "v").build();
+        assert!(matches!(err, Err(RedCrabError::InvalidParameter(_))));
+        let err = Transport::builder().header("x-token", "bad\nvalue").build();
+        assert!(matches!(err, Err(RedCrabError::InvalidParameter(_))));
+        let err = Transport::builder().user_agent("bad\nagent").build();
+        assert!(matches!(err, Err(RedCrabError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn limits_are_shared() {
+        let tran = Transport::new().unwrap().with_max_in_flight(Some(2));
+        let cloned = tran.clone();
+        let permit = tran.in_flight.as_ref().unwrap().try_acquire().unwrap();
+        assert_eq!(cloned.in_flight.as_ref().unwrap().available_permits(), 1);
+        drop(permit);
+        assert_eq!(cloned.in_flight.as_ref().unwrap().available_permits(), 2);
+
+        let tran = tran.with_rate_limiter(None).with_max_in_flight(None);
+        assert!(tran.limiter.is_none() && tran.in_flight.is_none());
+    }
+
+    #[tokio::test]
+    async fn retry_works() {
+        let policy = RetryPolicy::default()
+            .max_attempts(3)
+            .backoff(Duration::from_millis(50), Duration::from_secs(1))
+            .jitter(false);
+        let tran = Transport::from("http://127.0.0.1:1/")
+            .unwrap()
+            .with_retry_policy(policy);
+        let start = Instant::now();
+        let result = tran
+            .get::<String, ()>("stock_info_sh_name_code", None)
+            .await;
+        assert!(matches!(result.unwrap_err(), RedCrabError::ReqError(e) if e.is_connect()));
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
     #[tokio::test]
     async fn it_works() {
-        let t = Transport::new();
-        if let Ok(tran) = t {
-            let params = json! {{"symbol": "SH600519"}};
-
-            let url = tran.get_url("stock_cash_flow_sheet_by_yearly_em", Some(&params)).unwrap();
-            println!("{}", url.as_str());
-            let request: String = tran
-                .client
-                .get(url)
-                .send()
-                .await
-                .unwrap()
-                .text()
-                .await
-                .unwrap();
-            println!("{:?}", request);
-        }
+        let tran = Transport::builder()
+            .backend(Backend::Replay(
+                concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/akshare").into(),
+            ))
+            .build()
+            .unwrap();
+        let params = json! {{"symbol": "SH600519"}};
+
+        let url = tran
+            .get_url("stock_cash_flow_sheet_by_yearly_em", Some(&params))
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "http://127.0.0.1:8080/api/public/stock_cash_flow_sheet_by_yearly_em?symbol=SH600519"
+        );
+        let data: Vec<serde_json::Value> = tran
+            .get("stock_cash_flow_sheet_by_yearly_em", Some(&params))
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0]["SECURITY_NAME_ABBR"], "贵州茅台");
+
+        let err = tran
+            .get::<serde_json::Value, _>(
+                "stock_cash_flow_sheet_by_yearly_em",
+                Some(&json! {{"symbol": "SH000000"}}),
+            )
+            .await;
+        assert!(matches!(
+            err.unwrap_err(),
+            RedCrabError::MissingFixture { .. }
+        ));
     }
 
-    
+    #[tokio::test]
+    async fn record_works() {
+        let dir = std::env::temp_dir().join(format!("red-crab-record-{}", std::process::id()));
+        let tran = Transport::builder()
+            .base_url("http://127.0.0.1:1/api/public/")
+            .backend(Backend::Record(dir.clone()))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        assert!(tran
+            .get::<String, ()>("stock_info_sh_name_code", None)
+            .await
+            .is_err());
+        assert!(!dir.join("stock_info_sh_name_code.json").exists());
+    }
 }
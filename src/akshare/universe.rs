@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+use super::{shared::SymbolQuery, Akshare, Board, Exchange, Listing, Symbol};
+
+impl Akshare {
+    /// 上证暂停、终止上市公司
+    pub async fn sh_delist(&self) -> Result<Vec<DelistInfo>> {
+        self.transport
+            .get("stock_info_sh_delist", Some(&SymbolQuery::new("全部")))
+            .await
+    }
+
+    /// 深证终止上市公司
+    pub async fn sz_delist(&self) -> Result<Vec<DelistInfo>> {
+        self.transport
+            .get(
+                "stock_info_sz_delist",
+                Some(&SymbolQuery::new("终止上市公司")),
+            )
+            .await
+    }
+
+    /// 深证暂停上市公司
+    pub async fn sz_suspended(&self) -> Result<Vec<DelistInfo>> {
+        self.transport
+            .get(
+                "stock_info_sz_delist",
+                Some(&SymbolQuery::new("暂停上市公司")),
+            )
+            .await
+    }
+
+    /// 沪深暂停、终止上市的全部股票
+    pub async fn delisted(&self) -> Result<Vec<Symbol>> {
+        let (sh, sz, suspended) =
+            futures::try_join!(self.sh_delist(), self.sz_delist(), self.sz_suspended())?;
+        let sh = sh
+            .iter()
+            .filter_map(|x| Symbol::new(&x.code, Exchange::SH).ok());
+        let sz = sz
+            .iter()
+            .chain(suspended.iter())
+            .filter_map(|x| Symbol::new(&x.code, Exchange::SZ).ok());
+        Ok(sh.chain(sz).collect())
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct DelistInfo {
+    #[serde(alias = "公司代码")]
+    #[serde(alias = "证券代码")]
+    pub code: String,
+    #[serde(alias = "公司简称")]
+    #[serde(alias = "证券简称")]
+    pub name: String,
+    #[serde(alias = "上市日期")]
+    pub time: Option<String>,
+    /// 暂停或终止上市日期
+    #[serde(alias = "暂停上市日期")]
+    #[serde(alias = "终止上市日期")]
+    pub delist_time: Option<String>,
+}
+
+/// 选股范围，在沪深京股票列表上按条件过滤
+///
+/// ```no_run
+/// # async fn run() -> red_crab::Result<()> {
+/// use chrono::Local;
+/// use red_crab::akshare::{universe::Universe, Akshare, Board};
+///
+/// let akshare = Akshare::new()?;
+/// let universe = Universe::load(&akshare)
+///     .await?
+///     .min_listing_age(Local::now().date_naive(), 365)
+///     .exclude_st()
+///     .exclude_delisted()
+///     .boards(&[Board::Main, Board::ChiNext]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Universe {
+    listings: Vec<Listing>,
+    delisted: HashSet<Symbol>,
+}
+
+impl Universe {
+    pub fn new(listings: Vec<Listing>) -> Self {
+        Self {
+            listings,
+            delisted: HashSet::new(),
+        }
+    }
+
+    /// 暂停、终止上市的股票，供 [`exclude_delisted`](Self::exclude_delisted) 使用
+    pub fn with_delisted<I: IntoIterator<Item = Symbol>>(mut self, delisted: I) -> Self {
+        self.delisted.extend(delisted);
+        self
+    }
+
+    /// 沪深京全部 A 股及退市股票
+    pub async fn load(akshare: &Akshare) -> Result<Self> {
+        let (listings, delisted) = futures::try_join!(akshare.all_a_shares(), akshare.delisted())?;
+        Ok(Self::new(listings).with_delisted(delisted))
+    }
+
+    /// 只保留 `date`（含）之前上市的股票，上市日期未知的股票会被排除
+    pub fn listed_before(mut self, date: NaiveDate) -> Self {
+        self.listings
+            .retain(|x| x.listed.is_some_and(|t| t <= date));
+        self
+    }
+
+    /// 只保留截至 `today` 已上市满 `days` 天的股票
+    pub fn min_listing_age(self, today: NaiveDate, days: i64) -> Self {
+        self.listed_before(today - Duration::days(days))
+    }
+
+    /// 排除 ST、*ST 股票
+    pub fn exclude_st(mut self) -> Self {
+        self.listings.retain(|x| !is_st(&x.name));
+        self
+    }
+
+    /// 排除暂停、终止上市及进入退市整理期的股票
+    pub fn exclude_delisted(mut self) -> Self {
+        let delisted = &self.delisted;
+        self.listings
+            .retain(|x| !delisted.contains(&x.symbol) && !is_delisting(&x.name));
+        self
+    }
+
+    /// 只保留指定板块
+    pub fn boards(mut self, boards: &[Board]) -> Self {
+        self.listings.retain(|x| boards.contains(&x.symbol.board()));
+        self
+    }
+
+    pub fn listings(&self) -> &[Listing] {
+        &self.listings
+    }
+
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.listings.iter().map(|x| x.symbol.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.listings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.listings.is_empty()
+    }
+}
+
+/// 简称是否带 ST 标记：ST、*ST、S*ST、SST
+pub fn is_st(name: &str) -> bool {
+    let name = name.trim().to_ascii_uppercase();
+    ["ST", "*ST", "S*ST", "SST"]
+        .iter()
+        .any(|x| name.starts_with(x))
+}
+
+/// 退市整理期的股票简称以“退”开头或结尾
+fn is_delisting(name: &str) -> bool {
+    let name = name.trim();
+    name.starts_with('退') || name.ends_with('退')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::fixture_client;
+
+    fn listing(symbol: &str, name: &str, listed: &str) -> Listing {
+        Listing {
+            symbol: symbol.parse().unwrap(),
+            name: name.to_owned(),
+            listed: NaiveDate::parse_from_str(listed, "%Y-%m-%d").ok(),
+        }
+    }
+
+    #[test]
+    fn filters_work() {
+        let universe = Universe::new(vec![
+            listing("SZ002027", "分众传媒", "2004-08-04"),
+            listing("SZ300750", "宁德时代", "2018-06-11"),
+            listing("SH688981", "中芯国际", "2020-07-16"),
+            listing("SZ000004", "*ST国华", "1991-01-14"),
+            listing("SH600087", "退市长油", "1997-06-12"),
+            listing("SZ000003", "金田A", "1991-07-03"),
+            listing("BJ830799", "艾融软件", "2021-11-15"),
+            listing("SH600000", "浦发银行", ""),
+        ])
+        .with_delisted(["SZ000003".parse().unwrap()]);
+        let today = NaiveDate::from_ymd_opt(2022, 9, 1).unwrap();
+
+        let s = universe.clone().exclude_st().exclude_delisted();
+        assert_eq!(s.len(), 5);
+        let s = universe.clone().min_listing_age(today, 365 * 2);
+        assert_eq!(s.len(), 6);
+        assert!(s.listings().iter().all(|x| x.symbol.code() != "830799"));
+        let s = universe
+            .min_listing_age(today, 365)
+            .exclude_st()
+            .exclude_delisted()
+            .boards(&[Board::Main, Board::ChiNext]);
+        let symbols: Vec<String> = s.symbols().iter().map(|x| x.prefixed()).collect();
+        assert_eq!(symbols, vec!["SZ002027", "SZ300750"]);
+    }
+
+    #[test]
+    fn is_st_works() {
+        assert!(is_st("ST康美"));
+        assert!(is_st("*ST国华"));
+        assert!(is_st("S*ST前锋"));
+        assert!(!is_st("分众传媒"));
+    }
+
+    #[tokio::test]
+    async fn load_works() {
+        let client = fixture_client();
+        let delisted = client.delisted().await.unwrap();
+        assert_eq!(delisted.len(), 5);
+        assert!(delisted.contains(&"SZ000013".parse().unwrap()));
+
+        // SZ000001 同时出现在深证列表和暂停上市列表中
+        let universe = Universe::load(&client).await.unwrap();
+        assert_eq!(universe.len(), 8);
+        let universe = universe.exclude_delisted();
+        assert_eq!(universe.len(), 7);
+        assert!(!universe.symbols().contains(&"SZ000001".parse().unwrap()));
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, path::PathBuf, sync::Arc};
 use thiserror::Error;
 
 #[derive(Serialize, Deserialize, Debug, Error)]
@@ -69,6 +69,13 @@ pub enum RedCrabError {
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
 
+    #[error(transparent)]
+    CsvError(#[from] csv::Error),
+
+    #[cfg(feature = "store")]
+    #[error(transparent)]
+    SqliteError(#[from] rusqlite::Error),
+
     #[error(transparent)]
     ParseFloatError(#[from] std::num::ParseFloatError),
 
@@ -89,6 +96,55 @@ pub enum RedCrabError {
 
     #[error("{0}")]
     InvalidParameter(String),
+
+    /// 回放时找不到请求对应的录制文件
+    #[error("no fixture for {url} at {}", path.display())]
+    MissingFixture { url: String, path: PathBuf },
+
+    /// 异步 GET 请求合并后，无法复制给每个调用方的错误（如 [`RedCrabError::ReqError`]），
+    /// 用 [`RedCrabError::root`] 取出原始错误。
+    ///
+    /// 没有被合并的请求、以及 HTTP 状态码等可复制的错误不会被包装，与阻塞客户端返回的错误一致。
+    #[error(transparent)]
+    Shared(Arc<RedCrabError>),
+}
+
+impl RedCrabError {
+    /// 去掉 [`RedCrabError::Shared`] 包装后的错误
+    pub fn root(&self) -> &RedCrabError {
+        match self {
+            RedCrabError::Shared(e) => e.root(),
+            e => e,
+        }
+    }
+
+    /// 复制不含外部错误类型的错误，用于把合并请求的错误分发给每个调用方
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        use RedCrabError::*;
+        Some(match self {
+            NoMarketPair => NoMarketPair,
+            AssetNotFound() => AssetNotFound(),
+            NoApiKeySet() => NoApiKeySet(),
+            InternalServerError() => InternalServerError(),
+            ServiceUnavailable() => ServiceUnavailable(),
+            Unauthorized() => Unauthorized(),
+            SymbolNotFound() => SymbolNotFound(),
+            SocketError() => SocketError(),
+            WebSocketMessageNotSupported() => WebSocketMessageNotSupported(),
+            GetTimestampFailed() => GetTimestampFailed(),
+            PoisonError() => PoisonError(),
+            UnkownResponse(s) => UnkownResponse(s.clone()),
+            NotParsableResponse(s) => NotParsableResponse(s.clone()),
+            MissingParameter(s) => MissingParameter(s.clone()),
+            InvalidParameter(s) => InvalidParameter(s.clone()),
+            MissingFixture { url, path } => MissingFixture {
+                url: url.clone(),
+                path: path.clone(),
+            },
+            Shared(e) => Shared(e.clone()),
+            _ => return None,
+        })
+    }
 }
 
 pub type Result<T> = std::result::Result<T, crate::errors::RedCrabError>;
@@ -5,13 +5,20 @@ use crate::errors::Result;
 use crate::{
     akshare::Akshare,
     indicator::{Descriptors, ValueFactor},
+    provider::FinancialDataProvider,
 };
 
 #[derive(Clone)]
-pub struct FundamentalStep1(Akshare);
+pub struct FundamentalStep1<P = Akshare>(P);
+
+impl<P: FinancialDataProvider> FundamentalStep1<P> {
+    pub fn new(provider: P) -> Self {
+        Self(provider)
+    }
+}
 
 #[async_trait]
-impl ValueFactor for FundamentalStep1 {
+impl<P: FinancialDataProvider> ValueFactor for FundamentalStep1<P> {
     type Data = (f64, f64);
 
     fn get_info(&self) -> Descriptors {
@@ -53,30 +60,84 @@ impl ValueFactor for FundamentalStep1 {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
     use super::*;
+    use crate::akshare::{
+        balance_sheet::BalanceSheetData, cash_flow_statement::CashFlowStatementData,
+        income_statement::IncomeStatementData, StockCode, StockInfo,
+    };
+
+    struct MockProvider;
+
+    #[async_trait]
+    impl FinancialDataProvider for MockProvider {
+        async fn sh_list(&self) -> Result<Vec<StockInfo>> {
+            Ok(vec![])
+        }
+
+        async fn sz_list(&self) -> Result<Vec<StockInfo>> {
+            Ok(vec![])
+        }
+
+        async fn bj_list(&self) -> Result<Vec<StockInfo>> {
+            Ok(vec![])
+        }
+
+        async fn get_balance_sheet(
+            &self,
+            symbol: &StockCode,
+        ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+            let data: BalanceSheetData = serde_json::from_value(json!({
+                "SECUCODE": "002027.SZ",
+                "SECURITY_CODE": "002027",
+                "SECURITY_NAME_ABBR": "分众传媒",
+                "ORG_CODE": "10007267",
+                "ORG_TYPE": "通用",
+                "REPORT_DATE": "2022-03-31 00:00:00",
+                "REPORT_TYPE": "一季报",
+                "TOTAL_ASSETS": 20000000000.0,
+                "TOTAL_ASSETS_YOY": 15.0,
+            }))?;
+            assert_eq!(symbol.symbol(), "SZ002027");
+            Ok(BTreeMap::from([(data.report_date.clone(), data)]))
+        }
+
+        async fn get_income_statement(
+            &self,
+            _symbol: &StockCode,
+        ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+            Ok(BTreeMap::new())
+        }
+
+        async fn get_cash_flow_statement(
+            &self,
+            _symbol: &StockCode,
+        ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+            Ok(BTreeMap::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_provider_works() {
+        let step1 = FundamentalStep1::new(MockProvider);
+        let date = ReportDate::new(2022, crate::akshare::Quarter::Q1);
+        let data = step1.get_data("SZ002027", &date).await.unwrap();
+        assert_eq!(data, (20000000000.0, 15.0));
+        let score = step1.get_score("SZ002027", &date).await.unwrap();
+        assert!((score - 81.0).abs() < 1e-9);
+    }
 
     #[tokio::test]
     async fn it_works() {
-        let step1 = FundamentalStep1(Akshare::new().unwrap());
-        println!(
-            "{:?}",
-            step1
-                .get_score(
-                    "SZ002027",
-                    &ReportDate::new(2022, crate::akshare::Quarter::Q1)
-                )
-                .await
-                .unwrap()
-        );
-        println!(
-            "{:?}",
-            step1
-                .get_data(
-                    "SZ002027",
-                    &ReportDate::new(2022, crate::akshare::Quarter::Q1)
-                )
-                .await
-                .unwrap()
-        );
+        let step1 = FundamentalStep1(crate::akshare::fixture_client());
+        let date = ReportDate::new(2022, crate::akshare::Quarter::Q1);
+        let data = step1.get_data("SZ002027", &date).await.unwrap();
+        assert_eq!(data.0, 21184322311.66);
+        assert!((data.1 - 6.2709).abs() < 1e-3, "{:?}", data);
+        let score = step1.get_score("SZ002027", &date).await.unwrap();
+        assert!((score - 41.254).abs() < 1e-2, "{}", score);
     }
 }
@@ -5,13 +5,20 @@ use crate::errors::Result;
 use crate::{
     akshare::Akshare,
     indicator::{Descriptors, ValueFactor},
+    provider::FinancialDataProvider,
 };
 
 #[derive(Clone)]
-pub struct FundamentalStep1(Akshare);
+pub struct FundamentalStep2<P = Akshare>(P);
+
+impl<P: FinancialDataProvider> FundamentalStep2<P> {
+    pub fn new(provider: P) -> Self {
+        Self(provider)
+    }
+}
 
 #[async_trait]
-impl ValueFactor for FundamentalStep1 {
+impl<P: FinancialDataProvider> ValueFactor for FundamentalStep2<P> {
     type Data = (f64, f64);
 
     fn get_info(&self) -> Descriptors {
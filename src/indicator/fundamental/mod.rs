@@ -1,7 +1,5 @@
 mod fundamental_step1;
 mod fundamental_step2;
-use std::collections::HashMap;
 
-struct Cache<T: Sized> {
-    data: HashMap<String, HashMap<String, T>>,
-}
+pub use fundamental_step1::FundamentalStep1;
+pub use fundamental_step2::FundamentalStep2;
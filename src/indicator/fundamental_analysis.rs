@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use crate::errors::Result;
 use async_trait::async_trait;
 
@@ -28,7 +26,3 @@ impl ValueFactorService for FundamentalFactor {
         Some(&self.client)
     }
 }
-
-struct Cache<T: Sized> {
-    data: HashMap<String, HashMap<String, T>>,
-}
@@ -4,6 +4,7 @@ pub use errors::*;
 pub mod akshare;
 pub mod errors;
 pub mod indicator;
+pub mod provider;
 
 #[async_trait]
 pub trait ValueFactorService: Sized {
@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+#[cfg(feature = "store")]
+use red_crab::provider::{FinancialDataProvider, StatementStore};
+use red_crab::{
+    akshare::{Akshare, StockCode, Symbol},
+    provider::Snapshot,
+    Result,
+};
+
+#[derive(Parser)]
+#[command(name = "red-crab", version, about = "Financial Freedom Tool")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 将 akshare 数据快照到本地目录，供离线使用
+    Snapshot {
+        /// akshare 服务地址
+        #[arg(long, default_value = "http://127.0.0.1:8080/api/public/")]
+        url: String,
+        /// 输出目录，每次快照会在其下创建一个以时间命名的子目录
+        #[arg(long, default_value = "snapshots")]
+        out: PathBuf,
+        /// 股票代码，如 SZ002027、002027 或 002027.SZ；不指定时快照沪深京全部股票
+        #[arg(long, value_delimiter = ',')]
+        symbols: Vec<String>,
+    },
+    /// 增量同步报表到本地 SQLite 库
+    #[cfg(feature = "store")]
+    Sync {
+        /// akshare 服务地址
+        #[arg(long, default_value = "http://127.0.0.1:8080/api/public/")]
+        url: String,
+        /// SQLite 文件
+        #[arg(long, default_value = "red-crab.db")]
+        db: PathBuf,
+        /// 股票代码，如 SZ002027、002027 或 002027.SZ；不指定时同步沪深京全部股票
+        #[arg(long, value_delimiter = ',')]
+        symbols: Vec<String>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    match Cli::parse().command {
+        Command::Snapshot { url, out, symbols } => {
+            let snapshot = Snapshot::new(Akshare::from(&url)?, out);
+            let manifest = if symbols.is_empty() {
+                snapshot.run_all().await?
+            } else {
+                snapshot.run(&parse_symbols(&symbols)?).await?
+            };
+            println!(
+                "snapshot written to {}: {} symbols, {} failures",
+                snapshot.dir().display(),
+                manifest.symbol_count,
+                manifest.failures.len()
+            );
+        }
+        #[cfg(feature = "store")]
+        Command::Sync { url, db, symbols } => {
+            let akshare = Akshare::from(&url)?;
+            let store = StatementStore::open(&db)?;
+            let today = chrono::Local::now().date_naive();
+
+            let mut failures = store.sync_lists(&akshare, today).await?;
+            let symbols: Vec<StockCode> = if symbols.is_empty() {
+                store
+                    .all_a_shares()
+                    .await?
+                    .into_iter()
+                    .map(|x| x.symbol.into())
+                    .collect()
+            } else {
+                parse_symbols(&symbols)?
+            };
+            let report = store.sync(&akshare, &symbols, today).await?;
+            failures.extend(report.failures);
+            println!(
+                "synced {} into {}: {} fetched, {} skipped, {} rows changed, {} failures",
+                symbols.len(),
+                db.display(),
+                report.fetched,
+                report.skipped,
+                report.changed,
+                failures.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn parse_symbols(symbols: &[String]) -> Result<Vec<StockCode>> {
+    symbols
+        .iter()
+        .map(|x| x.parse::<Symbol>().map(StockCode::from))
+        .collect()
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+
+use crate::akshare::{
+    balance_sheet::BalanceSheetData, cash_flow_statement::CashFlowStatementData,
+    income_statement::IncomeStatementData, shared::by_report_date, ReportDate, StockCode,
+    StockInfo,
+};
+use crate::errors::Result;
+
+use super::FinancialDataProvider;
+
+/// 从本地目录读取 akshare 接口数据（JSON 或 CSV）
+///
+/// 目录结构与接口名一致：
+///
+/// ```text
+/// root/
+///   stock_info_sh_name_code.json
+///   stock_info_sz_name_code.csv
+///   stock_info_bj_name_code.json
+///   stock_balance_sheet_by_report_em/SZ002027.json
+///   stock_profit_sheet_by_report_em/SZ002027.csv
+///   stock_cash_flow_sheet_by_report_em/SZ002027.json
+/// ```
+///
+/// 同名文件同时存在时优先读取 JSON。
+#[derive(Clone, Debug)]
+pub struct LocalProvider {
+    root: PathBuf,
+}
+
+impl LocalProvider {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn code_list(&self, point: &str) -> Result<Vec<StockInfo>> {
+        self.read(self.root.join(point))
+    }
+
+    pub fn statement<T: DeserializeOwned>(
+        &self,
+        point: &str,
+        symbol: &StockCode,
+    ) -> Result<Vec<T>> {
+        self.read(self.root.join(point).join(symbol.symbol()))
+    }
+
+    fn read<T: DeserializeOwned>(&self, stem: PathBuf) -> Result<Vec<T>> {
+        let json = stem.with_extension("json");
+        if json.is_file() {
+            let reader = BufReader::new(File::open(json)?);
+            return Ok(serde_json::from_reader(reader)?);
+        }
+
+        let csv = stem.with_extension("csv");
+        if csv.is_file() {
+            let mut reader = csv::Reader::from_path(csv)?;
+            let data = reader.deserialize().collect::<csv::Result<Vec<T>>>()?;
+            return Ok(data);
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no json or csv file found for {}", stem.display()),
+        )
+        .into())
+    }
+}
+
+#[async_trait]
+impl FinancialDataProvider for LocalProvider {
+    async fn sh_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_sh_name_code")
+    }
+
+    async fn sz_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_sz_name_code")
+    }
+
+    async fn bj_list(&self) -> Result<Vec<StockInfo>> {
+        self.code_list("stock_info_bj_name_code")
+    }
+
+    async fn get_balance_sheet(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+        let data = self.statement("stock_balance_sheet_by_report_em", symbol)?;
+        Ok(by_report_date(data))
+    }
+
+    async fn get_income_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+        let data = self.statement("stock_profit_sheet_by_report_em", symbol)?;
+        Ok(by_report_date(data))
+    }
+
+    async fn get_cash_flow_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+        let data = self.statement("stock_cash_flow_sheet_by_report_em", symbol)?;
+        Ok(by_report_date(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::akshare::Quarter;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("red-crab-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[tokio::test]
+    async fn json_works() {
+        let root = temp_root("local-json");
+        fs::create_dir_all(root.join("stock_balance_sheet_by_report_em")).unwrap();
+        fs::write(
+            root.join("stock_balance_sheet_by_report_em/SZ002027.json"),
+            r#"[{"SECUCODE":"002027.SZ","SECURITY_CODE":"002027","SECURITY_NAME_ABBR":"分众传媒",
+                "ORG_CODE":"10007267","ORG_TYPE":"通用","REPORT_DATE":"2022-03-31 00:00:00",
+                "REPORT_TYPE":"一季报","TOTAL_ASSETS":20000000000.0,"TOTAL_ASSETS_YOY":null}]"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("stock_info_sh_name_code.json"),
+            r#"[{"证券代码":"600519","证券简称":"贵州茅台","上市日期":"2001-08-27"}]"#,
+        )
+        .unwrap();
+
+        let provider = LocalProvider::new(&root);
+        let s = provider
+            .get_balance_sheet(&"SZ002027".into())
+            .await
+            .unwrap();
+        let data = s.get(&ReportDate::new(2022, Quarter::Q1)).unwrap();
+        assert_eq!(data.total_assets, Some(20000000000.0));
+        assert_eq!(data.total_assets_yoy, None);
+
+        let list = provider.sh_list().await.unwrap();
+        assert_eq!(list[0].code, "600519");
+        assert!(provider.sz_list().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn csv_works() {
+        let root = temp_root("local-csv");
+        fs::create_dir_all(root.join("stock_profit_sheet_by_report_em")).unwrap();
+        fs::write(
+            root.join("stock_profit_sheet_by_report_em/SZ002027.csv"),
+            "SECUCODE,SECURITY_CODE,SECURITY_NAME_ABBR,ORG_CODE,ORG_TYPE,REPORT_DATE,REPORT_TYPE,PARENT_NETPROFIT\n\
+             002027.SZ,002027,分众传媒,10007267,通用,2021-12-31 00:00:00,年报,6064163522.44\n\
+             002027.SZ,002027,分众传媒,10007267,通用,2021-09-30 00:00:00,三季报,\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("stock_info_sz_name_code.csv"),
+            "A股代码,A股简称,A股上市日期\n002027,分众传媒,2004-08-04\n",
+        )
+        .unwrap();
+
+        let provider = LocalProvider::new(&root);
+        let s = provider
+            .get_income_statement(&"SZ002027".into())
+            .await
+            .unwrap();
+        assert_eq!(
+            s.get(&ReportDate::new(2021, Quarter::Q4))
+                .unwrap()
+                .parent_netprofit,
+            Some(6064163522.44)
+        );
+        assert_eq!(
+            s.get(&ReportDate::new(2021, Quarter::Q3))
+                .unwrap()
+                .parent_netprofit,
+            None
+        );
+
+        let list = provider.sz_list().await.unwrap();
+        assert_eq!(list[0].name, "分众传媒");
+    }
+}
@@ -0,0 +1,134 @@
+mod local;
+mod snapshot;
+#[cfg(feature = "store")]
+mod store;
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::akshare::{
+    balance_sheet::BalanceSheetData, cash_flow_statement::CashFlowStatementData,
+    income_statement::IncomeStatementData, listings, Akshare, Listing, ReportDate, StockCode,
+    StockInfo,
+};
+use crate::errors::Result;
+
+pub use local::LocalProvider;
+pub use snapshot::{Failure, Manifest, Snapshot, SNAPSHOT_VERSION};
+#[cfg(feature = "store")]
+pub use store::{StatementKind, StatementStore, StoredStatement, SyncReport, DEFAULT_RECHECK_DAYS};
+
+/// 财务数据来源：Akshare、本地文件、Mock 或其他供应商
+#[async_trait]
+pub trait FinancialDataProvider: Send + Sync {
+    /// 上证股票列表
+    async fn sh_list(&self) -> Result<Vec<StockInfo>>;
+    /// 深证股票列表
+    async fn sz_list(&self) -> Result<Vec<StockInfo>>;
+    /// 北交所股票列表
+    async fn bj_list(&self) -> Result<Vec<StockInfo>>;
+    /// 沪深京全部 A 股
+    async fn all_a_shares(&self) -> Result<Vec<Listing>> {
+        Ok(listings(
+            self.sh_list().await?,
+            self.sz_list().await?,
+            self.bj_list().await?,
+        ))
+    }
+    /// 资产负债表
+    async fn get_balance_sheet(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>>;
+    /// 利润表
+    async fn get_income_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>>;
+    /// 现金流量表
+    async fn get_cash_flow_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>>;
+}
+
+#[async_trait]
+impl FinancialDataProvider for Akshare {
+    async fn sh_list(&self) -> Result<Vec<StockInfo>> {
+        Akshare::sh_list(self).await
+    }
+
+    async fn sz_list(&self) -> Result<Vec<StockInfo>> {
+        Akshare::sz_list(self).await
+    }
+
+    async fn bj_list(&self) -> Result<Vec<StockInfo>> {
+        Akshare::bj_list(self).await
+    }
+
+    async fn all_a_shares(&self) -> Result<Vec<Listing>> {
+        Akshare::all_a_shares(self).await
+    }
+
+    async fn get_balance_sheet(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+        Akshare::get_balance_sheet(self, symbol).await
+    }
+
+    async fn get_income_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+        Akshare::get_income_statement(self, symbol).await
+    }
+
+    async fn get_cash_flow_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+        Akshare::get_cash_flow_statement(self, symbol).await
+    }
+}
+
+#[async_trait]
+impl<P: FinancialDataProvider + ?Sized> FinancialDataProvider for Arc<P> {
+    async fn sh_list(&self) -> Result<Vec<StockInfo>> {
+        (**self).sh_list().await
+    }
+
+    async fn sz_list(&self) -> Result<Vec<StockInfo>> {
+        (**self).sz_list().await
+    }
+
+    async fn bj_list(&self) -> Result<Vec<StockInfo>> {
+        (**self).bj_list().await
+    }
+
+    async fn all_a_shares(&self) -> Result<Vec<Listing>> {
+        (**self).all_a_shares().await
+    }
+
+    async fn get_balance_sheet(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+        (**self).get_balance_sheet(symbol).await
+    }
+
+    async fn get_income_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+        (**self).get_income_statement(symbol).await
+    }
+
+    async fn get_cash_flow_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+        (**self).get_cash_flow_statement(symbol).await
+    }
+}
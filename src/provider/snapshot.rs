@@ -0,0 +1,305 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::akshare::{Akshare, Exchange, StockCode, StockInfo, Symbol};
+use crate::errors::Result;
+
+/// 快照目录格式版本
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const LIST_POINTS: [(&str, Exchange); 3] = [
+    ("stock_info_sh_name_code", Exchange::SH),
+    ("stock_info_sz_name_code", Exchange::SZ),
+    ("stock_info_bj_name_code", Exchange::BJ),
+];
+
+const STATEMENT_POINTS: [&str; 3] = [
+    "stock_balance_sheet_by_report_em",
+    "stock_profit_sheet_by_report_em",
+    "stock_cash_flow_sheet_by_report_em",
+];
+
+/// 快照清单，写入 `manifest.json`
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Manifest {
+    pub version: u32,
+    pub fetched_at: DateTime<Utc>,
+    pub base_url: String,
+    pub symbol_count: usize,
+    pub failures: Vec<Failure>,
+}
+
+/// 拉取失败的接口
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Failure {
+    pub endpoint: String,
+    pub symbol: Option<String>,
+    pub error: String,
+}
+
+/// 将 akshare 返回的原始数据保存到本地，供 [`LocalProvider`](super::LocalProvider) 离线使用
+///
+/// 每次快照写入 `out/<UTC 时间，精确到微秒>/` 目录，结构与 `LocalProvider` 一致，并附带 `manifest.json`。
+/// 目录已存在时返回错误，不会覆盖已有快照。
+pub struct Snapshot {
+    akshare: Akshare,
+    fetched_at: DateTime<Utc>,
+    dir: PathBuf,
+}
+
+impl Snapshot {
+    pub fn new<P: AsRef<Path>>(akshare: Akshare, out: P) -> Self {
+        let fetched_at = Utc::now();
+        let dir = out
+            .as_ref()
+            .join(fetched_at.format("%Y%m%dT%H%M%S%.6fZ").to_string());
+        Self {
+            akshare,
+            fetched_at,
+            dir,
+        }
+    }
+
+    /// 本次快照的目录
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 快照沪深京股票列表及指定股票的三张报表
+    pub async fn run(&self, symbols: &[StockCode]) -> Result<Manifest> {
+        let mut failures = Vec::new();
+        self.create_dir()?;
+
+        for (point, _) in LIST_POINTS {
+            if let Err(e) = self.dump_list(point).await {
+                failures.push(Failure::new(point, None, e));
+            }
+        }
+        self.dump_statements(symbols, &mut failures).await?;
+
+        self.finish(symbols.len(), failures)
+    }
+
+    /// 快照沪深京股票列表中的全部股票
+    pub async fn run_all(&self) -> Result<Manifest> {
+        let mut failures = Vec::new();
+        let mut symbols = Vec::new();
+        self.create_dir()?;
+
+        for (point, exchange) in LIST_POINTS {
+            match self.dump_list(point).await {
+                Ok(list) => symbols.extend(
+                    list.iter()
+                        .filter_map(|x| Symbol::new(&x.code, exchange).ok())
+                        .map(StockCode::from),
+                ),
+                Err(e) => failures.push(Failure::new(point, None, e)),
+            }
+        }
+        self.dump_statements(&symbols, &mut failures).await?;
+
+        self.finish(symbols.len(), failures)
+    }
+
+    fn create_dir(&self) -> Result<()> {
+        if let Some(parent) = self.dir.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::create_dir(&self.dir)?;
+        Ok(())
+    }
+
+    async fn dump_list(&self, point: &str) -> Result<Vec<StockInfo>> {
+        let data: Value = self
+            .akshare
+            .transport
+            .get(point, None::<&StockCode>)
+            .await?;
+        let list = serde_json::from_value(data.clone())?;
+        write_json(&self.dir.join(point).with_extension("json"), &data)?;
+        Ok(list)
+    }
+
+    async fn dump_statements(
+        &self,
+        symbols: &[StockCode],
+        failures: &mut Vec<Failure>,
+    ) -> Result<()> {
+        for point in STATEMENT_POINTS {
+            fs::create_dir_all(self.dir.join(point))?;
+        }
+        for symbol in symbols {
+            for point in STATEMENT_POINTS {
+                match self
+                    .akshare
+                    .transport
+                    .get::<Value, _>(point, Some(symbol))
+                    .await
+                {
+                    Ok(data) => {
+                        let path = self
+                            .dir
+                            .join(point)
+                            .join(symbol.symbol())
+                            .with_extension("json");
+                        write_json(&path, &data)?;
+                    }
+                    Err(e) => {
+                        tracing::warn!("snapshot {} {} failed: {}", point, symbol.symbol(), e);
+                        failures.push(Failure::new(point, Some(symbol), e));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&self, symbol_count: usize, failures: Vec<Failure>) -> Result<Manifest> {
+        let manifest = Manifest {
+            version: SNAPSHOT_VERSION,
+            fetched_at: self.fetched_at,
+            base_url: self.akshare.base_url().to_owned(),
+            symbol_count,
+            failures,
+        };
+        write_json(&self.dir.join("manifest.json"), &manifest)?;
+        Ok(manifest)
+    }
+}
+
+impl Failure {
+    pub(crate) fn new(endpoint: &str, symbol: Option<&StockCode>, error: impl ToString) -> Self {
+        Self {
+            endpoint: endpoint.to_owned(),
+            symbol: symbol.map(|x| x.symbol().to_owned()),
+            error: error.to_string(),
+        }
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, data: &T) -> Result<()> {
+    let file = fs::File::create(path)?;
+    serde_json::to_writer_pretty(file, data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::{fixture_client, retry::RetryPolicy};
+    use crate::provider::{FinancialDataProvider, LocalProvider};
+
+    fn json<T: Serialize>(data: T) -> Value {
+        serde_json::to_value(data).unwrap()
+    }
+
+    #[tokio::test]
+    async fn round_trip_works() {
+        let out = std::env::temp_dir().join(format!("red-crab-round-trip-{}", std::process::id()));
+        let akshare = fixture_client();
+        let snapshot = Snapshot::new(akshare.clone(), &out);
+        let symbol: StockCode = "SZ002027".into();
+        let manifest = snapshot.run(std::slice::from_ref(&symbol)).await.unwrap();
+        assert!(manifest.failures.is_empty());
+        assert_eq!(manifest.symbol_count, 1);
+
+        let local = LocalProvider::new(snapshot.dir());
+        assert_eq!(
+            json(local.sh_list().await.unwrap()),
+            json(akshare.sh_list().await.unwrap())
+        );
+        assert_eq!(
+            json(local.sz_list().await.unwrap()),
+            json(akshare.sz_list().await.unwrap())
+        );
+        assert_eq!(
+            json(local.bj_list().await.unwrap()),
+            json(akshare.bj_list().await.unwrap())
+        );
+        assert_eq!(
+            json(
+                local
+                    .get_balance_sheet(&symbol)
+                    .await
+                    .unwrap()
+                    .values()
+                    .collect::<Vec<_>>()
+            ),
+            json(
+                akshare
+                    .get_balance_sheet(&symbol)
+                    .await
+                    .unwrap()
+                    .values()
+                    .collect::<Vec<_>>()
+            )
+        );
+        assert_eq!(
+            json(
+                local
+                    .get_income_statement(&symbol)
+                    .await
+                    .unwrap()
+                    .values()
+                    .collect::<Vec<_>>()
+            ),
+            json(
+                akshare
+                    .get_income_statement(&symbol)
+                    .await
+                    .unwrap()
+                    .values()
+                    .collect::<Vec<_>>()
+            )
+        );
+        assert_eq!(
+            json(
+                local
+                    .get_cash_flow_statement(&symbol)
+                    .await
+                    .unwrap()
+                    .values()
+                    .collect::<Vec<_>>()
+            ),
+            json(
+                akshare
+                    .get_cash_flow_statement(&symbol)
+                    .await
+                    .unwrap()
+                    .values()
+                    .collect::<Vec<_>>()
+            )
+        );
+
+        // 同一目录不会被第二次快照覆盖
+        assert!(snapshot.run(&[symbol]).await.is_err());
+        let _ = fs::remove_dir_all(out);
+    }
+
+    #[tokio::test]
+    async fn failures_are_recorded() {
+        let out = std::env::temp_dir().join(format!("red-crab-snapshot-{}", std::process::id()));
+        let akshare = Akshare::from("http://127.0.0.1:1/api/public/")
+            .unwrap()
+            .with_retry_policy(RetryPolicy::none());
+        let snapshot = Snapshot::new(akshare, &out);
+        let manifest = snapshot.run(&["SZ002027".into()]).await.unwrap();
+
+        assert_eq!(manifest.version, SNAPSHOT_VERSION);
+        assert_eq!(manifest.symbol_count, 1);
+        assert_eq!(manifest.failures.len(), 6);
+        assert_eq!(manifest.base_url, "http://127.0.0.1:1/api/public/");
+
+        let saved: Manifest =
+            serde_json::from_reader(fs::File::open(snapshot.dir().join("manifest.json")).unwrap())
+                .unwrap();
+        assert_eq!(saved.failures.len(), 6);
+        let _ = fs::remove_dir_all(out);
+    }
+}
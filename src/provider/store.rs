@@ -0,0 +1,650 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::Path,
+    sync::{Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, NaiveDate};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::akshare::{
+    balance_sheet::BalanceSheetData,
+    cash_flow_statement::CashFlowStatementData,
+    income_statement::IncomeStatementData,
+    shared::{by_report_date, ReportDated},
+    Quarter, ReportDate, StockCode, StockInfo,
+};
+use crate::errors::{RedCrabError, Result};
+
+use super::{Failure, FinancialDataProvider};
+
+/// 已是最新的股票，超过该天数后仍会重新拉取一次，以发现更正后的报表
+pub const DEFAULT_RECHECK_DAYS: i64 = 30;
+
+/// 按去年同期公告日估计披露日时，提前开始检查的天数
+const NOTICE_LEAD_DAYS: i64 = 3;
+
+/// 连续未拉到新报告期时，两次检查的最长间隔为 `2^MAX_BACKOFF_EXP` 天
+const MAX_BACKOFF_EXP: u32 = 3;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS statements (
+    kind        TEXT NOT NULL,
+    symbol      TEXT NOT NULL,
+    report_date TEXT NOT NULL,
+    notice_date TEXT,
+    update_date TEXT,
+    data        TEXT NOT NULL,
+    PRIMARY KEY (kind, symbol, report_date)
+);
+CREATE TABLE IF NOT EXISTS sync_state (
+    kind          TEXT NOT NULL,
+    symbol        TEXT NOT NULL,
+    checked_at    TEXT NOT NULL,
+    latest_report TEXT,
+    unchanged     INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (kind, symbol)
+);
+CREATE TABLE IF NOT EXISTS lists (
+    endpoint   TEXT PRIMARY KEY,
+    checked_at TEXT NOT NULL,
+    data       TEXT NOT NULL
+);
+";
+
+const LIST_POINTS: [&str; 3] = [
+    "stock_info_sh_name_code",
+    "stock_info_sz_name_code",
+    "stock_info_bj_name_code",
+];
+
+/// 报表类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatementKind {
+    BalanceSheet,
+    IncomeStatement,
+    CashFlowStatement,
+}
+
+impl StatementKind {
+    pub const ALL: [StatementKind; 3] = [
+        StatementKind::BalanceSheet,
+        StatementKind::IncomeStatement,
+        StatementKind::CashFlowStatement,
+    ];
+
+    /// 对应的 akshare 接口名
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            StatementKind::BalanceSheet => "stock_balance_sheet_by_report_em",
+            StatementKind::IncomeStatement => "stock_profit_sheet_by_report_em",
+            StatementKind::CashFlowStatement => "stock_cash_flow_sheet_by_report_em",
+        }
+    }
+}
+
+/// 可以存入 [`StatementStore`] 的报表
+pub trait StoredStatement: ReportDated + Serialize + DeserializeOwned {
+    const KIND: StatementKind;
+
+    fn notice_date(&self) -> Option<&str>;
+    fn update_date(&self) -> Option<&str>;
+}
+
+impl StoredStatement for BalanceSheetData {
+    const KIND: StatementKind = StatementKind::BalanceSheet;
+
+    fn notice_date(&self) -> Option<&str> {
+        self.notice_date.as_deref()
+    }
+
+    fn update_date(&self) -> Option<&str> {
+        self.update_date.as_deref()
+    }
+}
+
+impl StoredStatement for IncomeStatementData {
+    const KIND: StatementKind = StatementKind::IncomeStatement;
+
+    fn notice_date(&self) -> Option<&str> {
+        self.notice_date.as_deref()
+    }
+
+    fn update_date(&self) -> Option<&str> {
+        self.update_date.as_deref()
+    }
+}
+
+impl StoredStatement for CashFlowStatementData {
+    const KIND: StatementKind = StatementKind::CashFlowStatement;
+
+    fn notice_date(&self) -> Option<&str> {
+        self.notice_date.as_deref()
+    }
+
+    fn update_date(&self) -> Option<&str> {
+        self.update_date.as_deref()
+    }
+}
+
+/// 一次增量同步的结果
+#[derive(Clone, Debug, Default)]
+pub struct SyncReport {
+    /// 重新拉取的报表数（股票 × 报表类型）
+    pub fetched: usize,
+    /// 判定无需拉取而跳过的报表数
+    pub skipped: usize,
+    /// 新增或内容有变化的报告期行数
+    pub changed: usize,
+    pub failures: Vec<Failure>,
+}
+
+/// 基于 SQLite 的本地报表库，按股票代码 + 报告期保存三张报表
+///
+/// [`sync`](Self::sync) 只重新拉取可能有新报表的股票，见 [`is_due`](Self::is_due)。
+///
+/// 本身也实现了 [`FinancialDataProvider`]，可以直接交给指标计算离线使用。
+pub struct StatementStore {
+    conn: Mutex<Connection>,
+    recheck_after: Duration,
+}
+
+impl StatementStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            recheck_after: Duration::days(DEFAULT_RECHECK_DAYS),
+        })
+    }
+
+    pub fn with_recheck_after(mut self, recheck_after: Duration) -> Self {
+        self.recheck_after = recheck_after;
+        self
+    }
+
+    /// 写入报表，返回新增或内容有变化的行数
+    pub fn save<'a, T, I>(&self, symbol: &StockCode, data: I) -> Result<usize>
+    where
+        T: StoredStatement + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        let mut changed = 0;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO statements (kind, symbol, report_date, notice_date, update_date, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT (kind, symbol, report_date) DO UPDATE SET
+                     notice_date = excluded.notice_date,
+                     update_date = excluded.update_date,
+                     data = excluded.data
+                 WHERE statements.data IS NOT excluded.data",
+            )?;
+            for d in data {
+                changed += stmt.execute(params![
+                    T::KIND.endpoint(),
+                    symbol.symbol(),
+                    d.report_date().to_string(),
+                    d.notice_date(),
+                    d.update_date(),
+                    serde_json::to_string(d)?,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// 读取某只股票的全部报表，按报告期升序
+    pub fn load<T: StoredStatement>(&self, symbol: &StockCode) -> Result<Vec<T>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT data FROM statements WHERE kind = ?1 AND symbol = ?2 ORDER BY report_date",
+        )?;
+        let rows = stmt.query_map(params![T::KIND.endpoint(), symbol.symbol()], |row| {
+            row.get::<_, String>(0)
+        })?;
+
+        let mut data = Vec::new();
+        for row in rows {
+            data.push(serde_json::from_str(&row?)?);
+        }
+        Ok(data)
+    }
+
+    /// 本地最新的报告期
+    pub fn latest_report(&self, kind: StatementKind, symbol: &StockCode) -> Result<Option<String>> {
+        let conn = self.conn()?;
+        let latest = conn.query_row(
+            "SELECT MAX(report_date) FROM statements WHERE kind = ?1 AND symbol = ?2",
+            params![kind.endpoint(), symbol.symbol()],
+            |row| row.get(0),
+        )?;
+        Ok(latest)
+    }
+
+    /// 某一报告期的公告日，没有公告日时取更新日期
+    pub fn notice_date(
+        &self,
+        kind: StatementKind,
+        symbol: &StockCode,
+        report_date: &ReportDate,
+    ) -> Result<Option<NaiveDate>> {
+        let conn = self.conn()?;
+        let notice: Option<Option<String>> = conn
+            .query_row(
+                "SELECT COALESCE(notice_date, update_date) FROM statements
+                 WHERE kind = ?1 AND symbol = ?2 AND report_date = ?3",
+                params![kind.endpoint(), symbol.symbol(), report_date.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(notice
+            .flatten()
+            .and_then(|x| NaiveDate::parse_from_str(x.get(..10)?, "%Y-%m-%d").ok()))
+    }
+
+    /// 是否需要重新拉取
+    ///
+    /// - 从未检查过：需要；当天已检查过：不需要；距上次检查超过 `recheck_after`：需要；
+    /// - 本地已有最近一个已结束季度的报表：不需要；
+    /// - 否则待披露的是本地最新报告期的下一期：
+    ///   - 上次检查在法定披露截止日之前、今天已过截止日：需要；
+    ///   - 按去年同期公告日估计的披露日（提前 3 天）之前：不需要；
+    ///   - 连续 n 次未拉到新报告期时，距上次检查满 `2^n` 天（最多 8 天）才需要。
+    pub fn is_due(
+        &self,
+        kind: StatementKind,
+        symbol: &StockCode,
+        today: NaiveDate,
+    ) -> Result<bool> {
+        let state: Option<(String, Option<String>, u32)> = self
+            .conn()?
+            .query_row(
+                "SELECT checked_at, latest_report, unchanged FROM sync_state
+                 WHERE kind = ?1 AND symbol = ?2",
+                params![kind.endpoint(), symbol.symbol()],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+
+        let (checked_at, latest_report, unchanged) = match state {
+            Some(state) => state,
+            None => return Ok(true),
+        };
+        let checked_at = NaiveDate::parse_from_str(&checked_at, "%Y-%m-%d")
+            .map_err(|e| RedCrabError::NotParsableResponse(e.to_string()))?;
+        if checked_at >= today {
+            return Ok(false);
+        }
+        if today - checked_at >= self.recheck_after {
+            return Ok(true);
+        }
+
+        let expected = ReportDate::try_from(latest_quarter_end(today))?;
+        let pending = match latest_report.map(|x| x.parse::<ReportDate>()).transpose()? {
+            Some(latest) if latest >= expected => return Ok(false),
+            Some(latest) => latest.next_quarter(),
+            None => expected,
+        };
+
+        let deadline = disclosure_deadline(&pending);
+        if checked_at <= deadline && deadline < today {
+            return Ok(true);
+        }
+        if let Some(notice) = self.notice_date(kind, symbol, &pending.year_ago())? {
+            let estimated = (notice + Duration::days(365)).min(deadline);
+            if today < estimated - Duration::days(NOTICE_LEAD_DAYS) {
+                return Ok(false);
+            }
+        }
+        let backoff = Duration::days(1 << unchanged.min(MAX_BACKOFF_EXP));
+        Ok(today - checked_at >= backoff)
+    }
+
+    /// 增量同步指定股票的三张报表，单只股票失败不影响其余股票
+    pub async fn sync<P>(
+        &self,
+        provider: &P,
+        symbols: &[StockCode],
+        today: NaiveDate,
+    ) -> Result<SyncReport>
+    where
+        P: FinancialDataProvider + ?Sized,
+    {
+        let mut report = SyncReport::default();
+        for symbol in symbols {
+            for kind in StatementKind::ALL {
+                if !self.is_due(kind, symbol, today)? {
+                    report.skipped += 1;
+                    continue;
+                }
+
+                let changed = match kind {
+                    StatementKind::BalanceSheet => provider
+                        .get_balance_sheet(symbol)
+                        .await
+                        .and_then(|x| self.save(symbol, x.values())),
+                    StatementKind::IncomeStatement => provider
+                        .get_income_statement(symbol)
+                        .await
+                        .and_then(|x| self.save(symbol, x.values())),
+                    StatementKind::CashFlowStatement => provider
+                        .get_cash_flow_statement(symbol)
+                        .await
+                        .and_then(|x| self.save(symbol, x.values())),
+                };
+                match changed {
+                    Ok(changed) => {
+                        self.mark_checked(kind, symbol, today)?;
+                        report.fetched += 1;
+                        report.changed += changed;
+                    }
+                    Err(e) => report
+                        .failures
+                        .push(Failure::new(kind.endpoint(), Some(symbol), e)),
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// 同步沪深京股票列表
+    pub async fn sync_lists<P>(&self, provider: &P, today: NaiveDate) -> Result<Vec<Failure>>
+    where
+        P: FinancialDataProvider + ?Sized,
+    {
+        let mut failures = Vec::new();
+        for point in LIST_POINTS {
+            let list = match point {
+                "stock_info_sh_name_code" => provider.sh_list().await,
+                "stock_info_sz_name_code" => provider.sz_list().await,
+                _ => provider.bj_list().await,
+            };
+            match list {
+                Ok(list) => {
+                    let conn = self.conn()?;
+                    conn.execute(
+                        "INSERT OR REPLACE INTO lists (endpoint, checked_at, data) VALUES (?1, ?2, ?3)",
+                        params![point, today.to_string(), serde_json::to_string(&list)?],
+                    )?;
+                }
+                Err(e) => failures.push(Failure::new(point, None, e)),
+            }
+        }
+        Ok(failures)
+    }
+
+    fn mark_checked(
+        &self,
+        kind: StatementKind,
+        symbol: &StockCode,
+        today: NaiveDate,
+    ) -> Result<()> {
+        let latest_report = self.latest_report(kind, symbol)?;
+        let conn = self.conn()?;
+        let previous: Option<(Option<String>, u32)> = conn
+            .query_row(
+                "SELECT latest_report, unchanged FROM sync_state WHERE kind = ?1 AND symbol = ?2",
+                params![kind.endpoint(), symbol.symbol()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let unchanged = match previous {
+            Some((previous, unchanged)) if previous >= latest_report => unchanged + 1,
+            _ => 0,
+        };
+        conn.execute(
+            "INSERT OR REPLACE INTO sync_state (kind, symbol, checked_at, latest_report, unchanged)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                kind.endpoint(),
+                symbol.symbol(),
+                today.to_string(),
+                latest_report,
+                unchanged
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn list(&self, point: &str) -> Result<Vec<StockInfo>> {
+        let conn = self.conn()?;
+        let data: Option<String> = conn
+            .query_row(
+                "SELECT data FROM lists WHERE endpoint = ?1",
+                params![point],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match data {
+            Some(data) => Ok(serde_json::from_str(&data)?),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} has not been synced", point),
+            )
+            .into()),
+        }
+    }
+
+    fn conn(&self) -> Result<MutexGuard<'_, Connection>> {
+        self.conn.lock().map_err(|_| RedCrabError::PoisonError())
+    }
+}
+
+#[async_trait]
+impl FinancialDataProvider for StatementStore {
+    async fn sh_list(&self) -> Result<Vec<StockInfo>> {
+        self.list("stock_info_sh_name_code")
+    }
+
+    async fn sz_list(&self) -> Result<Vec<StockInfo>> {
+        self.list("stock_info_sz_name_code")
+    }
+
+    async fn bj_list(&self) -> Result<Vec<StockInfo>> {
+        self.list("stock_info_bj_name_code")
+    }
+
+    async fn get_balance_sheet(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, BalanceSheetData>> {
+        Ok(by_report_date(self.load(symbol)?))
+    }
+
+    async fn get_income_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, IncomeStatementData>> {
+        Ok(by_report_date(self.load(symbol)?))
+    }
+
+    async fn get_cash_flow_statement(
+        &self,
+        symbol: &StockCode,
+    ) -> Result<BTreeMap<ReportDate, CashFlowStatementData>> {
+        Ok(by_report_date(self.load(symbol)?))
+    }
+}
+
+/// 最近一个已结束的季度的最后一天
+fn latest_quarter_end(today: NaiveDate) -> NaiveDate {
+    let month = (today.month0() / 3) * 3 + 1;
+    NaiveDate::from_ymd_opt(today.year(), month, 1).unwrap() - Duration::days(1)
+}
+
+/// 法定披露截止日：一季报 4 月底、半年报 8 月底、三季报 10 月底、年报次年 4 月底
+fn disclosure_deadline(report_date: &ReportDate) -> NaiveDate {
+    let (year, month, day) = match report_date.quarter {
+        Quarter::Q1 => (report_date.year, 4, 30),
+        Quarter::Q2 => (report_date.year, 8, 31),
+        Quarter::Q3 => (report_date.year, 10, 31),
+        Quarter::Q4 => (report_date.year + 1, 4, 30),
+    };
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::akshare::fixture_client;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn latest_quarter_end_works() {
+        assert_eq!(latest_quarter_end(date("2022-09-01")), date("2022-06-30"));
+        assert_eq!(latest_quarter_end(date("2022-10-01")), date("2022-09-30"));
+        assert_eq!(latest_quarter_end(date("2022-01-15")), date("2021-12-31"));
+    }
+
+    #[tokio::test]
+    async fn sync_works() {
+        let akshare = fixture_client();
+        let store = StatementStore::open_in_memory().unwrap();
+        let symbols = vec![StockCode::from("SZ002027")];
+
+        let report = store
+            .sync(&akshare, &symbols, date("2022-09-01"))
+            .await
+            .unwrap();
+        assert_eq!(report.fetched, 3);
+        assert_eq!(report.changed, 21);
+        assert!(report.failures.is_empty());
+
+        let s = store.get_balance_sheet(&symbols[0]).await.unwrap();
+        let data = s.get(&ReportDate::new(2022, Quarter::Q1)).unwrap();
+        assert_eq!(data.total_assets, Some(21184322311.66));
+        let s = store.get_income_statement(&symbols[0]).await.unwrap();
+        assert_eq!(
+            s.get(&ReportDate::new(2021, Quarter::Q4))
+                .unwrap()
+                .parent_netprofit,
+            Some(6064163522.44)
+        );
+
+        // 同一天内不再拉取，2022Q2 已入库时也不拉取
+        let report = store
+            .sync(&akshare, &symbols, date("2022-09-01"))
+            .await
+            .unwrap();
+        assert_eq!((report.fetched, report.skipped), (0, 3));
+        let report = store
+            .sync(&akshare, &symbols, date("2022-09-20"))
+            .await
+            .unwrap();
+        assert_eq!((report.fetched, report.skipped), (0, 3));
+
+        // 超过 recheck_after 后重新检查
+        let store = store.with_recheck_after(Duration::days(7));
+        assert!(store
+            .is_due(StatementKind::BalanceSheet, &symbols[0], date("2022-09-20"))
+            .unwrap());
+
+        // 重新检查时数据没有变化
+        let report = store
+            .sync(&akshare, &symbols, date("2022-10-15"))
+            .await
+            .unwrap();
+        assert_eq!((report.fetched, report.changed), (3, 0));
+    }
+
+    #[tokio::test]
+    async fn disclosure_schedule_works() {
+        let akshare = fixture_client();
+        let store = StatementStore::open_in_memory().unwrap();
+        let symbols = vec![StockCode::from("SZ002027")];
+        let due = |today| {
+            store
+                .is_due(StatementKind::IncomeStatement, &symbols[0], date(today))
+                .unwrap()
+        };
+
+        // 2022Q3 已结束但尚未披露，去年三季报 2021-10-28 公告
+        let report = store
+            .sync(&akshare, &symbols, date("2022-10-01"))
+            .await
+            .unwrap();
+        assert_eq!(report.fetched, 3);
+        assert!(!due("2022-10-10"));
+        assert!(due("2022-10-25"));
+
+        let report = store
+            .sync(&akshare, &symbols, date("2022-10-25"))
+            .await
+            .unwrap();
+        assert_eq!((report.fetched, report.changed), (3, 0));
+        // 昨天检查过且没有新报告期，今天不再拉取
+        assert!(!due("2022-10-26"));
+        assert!(due("2022-10-27"));
+
+        store
+            .sync(&akshare, &symbols, date("2022-10-27"))
+            .await
+            .unwrap();
+        assert!(!due("2022-10-30"));
+        // 过了 10 月底的截止日再检查一次
+        assert!(due("2022-11-01"));
+    }
+
+    #[test]
+    fn disclosure_deadline_works() {
+        assert_eq!(
+            disclosure_deadline(&ReportDate::new(2022, Quarter::Q4)),
+            date("2023-04-30")
+        );
+        assert_eq!(
+            disclosure_deadline(&ReportDate::new(2022, Quarter::Q2)),
+            date("2022-08-31")
+        );
+    }
+
+    #[tokio::test]
+    async fn failures_are_recorded() {
+        let akshare = fixture_client();
+        let store = StatementStore::open_in_memory().unwrap();
+        let symbols = vec![StockCode::from("SZ000000")];
+
+        let report = store
+            .sync(&akshare, &symbols, date("2022-09-01"))
+            .await
+            .unwrap();
+        assert_eq!(report.fetched, 0);
+        assert_eq!(report.failures.len(), 3);
+        assert!(store
+            .is_due(StatementKind::BalanceSheet, &symbols[0], date("2022-09-01"))
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn lists_work() {
+        let akshare = fixture_client();
+        let store = StatementStore::open_in_memory().unwrap();
+        assert!(store.sh_list().await.is_err());
+
+        let failures = store
+            .sync_lists(&akshare, date("2022-09-01"))
+            .await
+            .unwrap();
+        assert!(failures.is_empty());
+        assert_eq!(store.sh_list().await.unwrap().len(), 3);
+        assert_eq!(store.sz_list().await.unwrap()[0].code, "000001");
+        assert_eq!(store.all_a_shares().await.unwrap().len(), 8);
+    }
+}